@@ -0,0 +1,30 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use serde::Serialize;
+use std::time::Duration;
+
+#[derive(Serialize)]
+struct OutHuman {
+    #[serde(with = "serde_ext_duration::human")]
+    t: Duration,
+}
+
+fn human(c: &mut Criterion) {
+    let inputs = [
+        Duration::ZERO,
+        Duration::from_millis(250),
+        Duration::from_millis(65_000),
+        Duration::from_millis(3_723_250),
+        Duration::from_millis(93_784_005),
+        Duration::MAX,
+    ];
+    c.bench_function("serialize_human", |b| {
+        b.iter(|| {
+            for t in inputs {
+                black_box(serde_json::to_string(&OutHuman { t: black_box(t) }).unwrap());
+            }
+        })
+    });
+}
+
+criterion_group!(benches, human);
+criterion_main!(benches);
@@ -0,0 +1,106 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//! Companion attribute macro for `serde_ext_duration` (enable its `derive` feature).
+//!
+//! `#[durations]` rewrites `#[duration(...)]` field attributes into the matching
+//! `#[serde(with = "...")]`, so the module paths never have to be spelled out:
+//! - `#[duration]`                              → `serde_ext_duration::human`
+//! - `#[duration(format = "millis")]`           → `serde_ext_duration::millis`
+//! - `#[duration(format = "secs", optional)]`   → `#[serde(default, with = "serde_ext_duration::opt::secs")]`
+//!
+//! Place `#[durations]` above `#[derive(Serialize, Deserialize)]`.
+
+use proc_macro::TokenStream;
+use quote::ToTokens;
+use syn::{parse_macro_input, parse_quote, Attribute, Data, DeriveInput, Error, LitStr, Meta};
+
+/// `format` names and the module implementing each one.
+const FORMATS: &[(&str, &str)] = &[
+    ("human", "human"),
+    ("secs", "secs"),
+    ("millis", "millis"),
+    ("secs_f64_ms", "secs_f64_ms"),
+    ("nanos", "nanos_in"),
+    ("centis", "centis"),
+    ("iso8601", "iso8601"),
+];
+
+#[proc_macro_attribute]
+pub fn durations(args: TokenStream, input: TokenStream) -> TokenStream {
+    if !args.is_empty() {
+        let args = proc_macro2::TokenStream::from(args);
+        return Error::new_spanned(args, "#[durations] takes no arguments").to_compile_error().into();
+    }
+    let mut item = parse_macro_input!(input as DeriveInput);
+    match expand(&mut item) {
+        Ok(()) => item.into_token_stream().into(),
+        Err(e) => e.to_compile_error().into(),
+    }
+}
+
+fn expand(item: &mut DeriveInput) -> syn::Result<()> {
+    let fields: Vec<&mut syn::Field> = match &mut item.data {
+        Data::Struct(s) => s.fields.iter_mut().collect(),
+        Data::Enum(e) => e.variants.iter_mut().flat_map(|v| v.fields.iter_mut()).collect(),
+        Data::Union(u) => return Err(Error::new(u.union_token.span, "#[durations] does not support unions")),
+    };
+    for field in fields {
+        let mut with = None;
+        let mut kept = Vec::with_capacity(field.attrs.len());
+        for attr in field.attrs.drain(..) {
+            if !attr.path().is_ident("duration") {
+                kept.push(attr);
+                continue;
+            }
+            if with.is_some() {
+                return Err(Error::new_spanned(attr, "duplicate #[duration] attribute"));
+            }
+            with = Some(serde_attr(&attr)?);
+        }
+        kept.extend(with);
+        field.attrs = kept;
+    }
+    Ok(())
+}
+
+/// Translate one `#[duration(...)]` into the equivalent `#[serde(...)]`.
+fn serde_attr(attr: &Attribute) -> syn::Result<Attribute> {
+    let mut format: Option<LitStr> = None;
+    let mut optional = false;
+    if !matches!(attr.meta, Meta::Path(_)) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("format") {
+                format = Some(meta.value()?.parse()?);
+                Ok(())
+            } else if meta.path.is_ident("optional") {
+                optional = true;
+                Ok(())
+            } else {
+                Err(meta.error("expected `format = \"...\"` or `optional`"))
+            }
+        })?;
+    }
+
+    let module = match &format {
+        None => "human",
+        Some(lit) => {
+            let name = lit.value();
+            match FORMATS.iter().find(|(f, _)| *f == name) {
+                Some((_, module)) => module,
+                None => {
+                    let known: Vec<_> = FORMATS.iter().map(|(f, _)| *f).collect();
+                    let msg = format!("unknown duration format `{name}` (use {})", known.join(", "));
+                    return Err(Error::new(lit.span(), msg));
+                }
+            }
+        }
+    };
+
+    let span = format.as_ref().map_or_else(proc_macro2::Span::call_site, LitStr::span);
+    Ok(if optional {
+        let path = LitStr::new(&format!("::serde_ext_duration::opt::{module}"), span);
+        parse_quote!(#[serde(default, with = #path)])
+    } else {
+        let path = LitStr::new(&format!("::serde_ext_duration::{module}"), span);
+        parse_quote!(#[serde(with = #path)])
+    })
+}
@@ -7,166 +7,3831 @@
 //! - `#[serde(with = "serde_ext_duration::secs")]`    → u64 seconds
 //! - `#[serde(with = "serde_ext_duration::millis")]`  → u64 milliseconds
 //! - `#[serde(with = "serde_ext_duration::secs_f64_ms")]` → f64 seconds (3 decimals)
+//! - `#[serde(with = "serde_ext_duration::nanos_in")]` → u64 nanoseconds; bare integers read as nanoseconds
+//! - `#[serde(with = "serde_ext_duration::in_minutes")]` → one fixed unit, e.g. `"90m"` (also `in_days`, `in_hours`, `in_seconds`, `in_millis`)
 //!
-//! Deserialization accepts **int / float / string** (units: d, h, m, s, ms).
+//! With the `derive` feature, `#[serde_ext_duration::durations]` maps `#[duration(format = "millis", optional)]`
+//! field attributes to those modules (formats: human, secs, millis, secs_f64_ms, nanos, centis, iso8601).
+//! With the `rust_decimal` feature, `decimal_secs` writes seconds as an exact `Decimal`; with
+//! `jiff`, `jiff_friendly` uses the `jiff::SignedDuration` friendly format.
+//!
+//! Deserialization accepts **int / float / string** (units: d, h, m, s, ms, plus every `humantime`
+//! spelling such as `us`, `ns`, `w`, `min`, `hours`; fractions like `"1.5h"`).
+//!
+//! ## Modules that take arguments
+//!
+//! `with` names a module and cannot pass it arguments, so functions that need one (a frame rate,
+//! a tempo, a default, a cap, an allowed set, a threshold) are called from a small module of your
+//! own that fixes it:
+//!
+//! ```
+//! mod fps30 {
+//!     use serde::{Deserializer, Serializer};
+//!     use std::time::Duration;
+//!     pub fn serialize<S: Serializer>(d: &Duration, s: S) -> Result<S::Ok, S::Error> {
+//!         serde_ext_duration::frames::serialize(d, 30.0, s)
+//!     }
+//!     pub fn deserialize<'de, D: Deserializer<'de>>(d: D) -> Result<Duration, D::Error> {
+//!         serde_ext_duration::frames::deserialize(d, 30.0)
+//!     }
+//! }
+//!
+//! #[derive(serde::Serialize, serde::Deserialize)]
+//! struct Clip {
+//!     #[serde(with = "fps30")]
+//!     length: std::time::Duration,
+//! }
+//! ```
+
+#[cfg(feature = "derive")]
+pub use serde_ext_duration_derive::durations;
 
 use serde::de::{self, Deserialize, Deserializer, Visitor};
 use serde::ser::{Serialize, Serializer};
-use std::{fmt, time::Duration};
+use std::{fmt, fmt::Write as _, time::Duration};
+
+/// Shared visitor behind every flexible deserializer; `parse` handles the string case,
+/// `int_nanos` is the length of one integer step (seconds by default), `float_error` rejects floats
+/// with its message when set, and `overflow` resolves numbers past `Duration::MAX` (strings overflow inside `parse`).
+struct DurVisitor {
+    parse: fn(&str) -> Result<Duration, String>,
+    int_nanos: u128,
+    float_error: Option<&'static str>,
+    overflow: OverflowPolicy,
+}
+
+impl Default for DurVisitor {
+    fn default() -> Self {
+        DurVisitor {
+            parse: parse_str,
+            int_nanos: Unit::Seconds.nanos(),
+            float_error: None,
+            overflow: OverflowPolicy::Error,
+        }
+    }
+}
+
+impl Visitor<'_> for DurVisitor {
+    type Value = Duration;
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let ints = match self.int_nanos {
+            1 => "integer nanoseconds",
+            1_000_000 => "integer milliseconds",
+            10_000_000 => "integer centiseconds",
+            _ => "integer seconds",
+        };
+        write!(f, "{ints}, float seconds.millis, or a string like '1h 23m 45s' / '123s' / '250ms'")
+    }
+    fn visit_u64<E>(self, v: u64) -> Result<Duration, E>
+    where
+        E: de::Error,
+    {
+        // u64 × one day in nanos still fits u128, only `Duration` can overflow
+        self.overflow.fit_duration(v as u128 * self.int_nanos).map_err(E::custom)
+    }
+    fn visit_i64<E>(self, v: i64) -> Result<Duration, E>
+    where
+        E: de::Error,
+    {
+        // No arithmetic before the sign check, so `i64::MIN` is just another negative.
+        match u64::try_from(v) {
+            Ok(v) => self.visit_u64(v),
+            Err(_) => Err(E::custom("negative duration not allowed")),
+        }
+    }
+    fn visit_f64<E>(self, v: f64) -> Result<Duration, E>
+    where
+        E: de::Error,
+    {
+        if let Some(msg) = self.float_error {
+            // integers past `u64::MAX` reach here as floats in formats like JSON
+            if !(v >= TWO_POW_64 && v.is_finite() && v.fract() == 0.0) {
+                return Err(E::custom(msg));
+            }
+        }
+        if !v.is_finite() {
+            return Err(E::custom("non-finite float"));
+        }
+        if v < 0.0 {
+            return Err(E::custom("negative duration not allowed"));
+        }
+        // Range check before any cast: `as u64` would silently saturate past `u64::MAX` seconds.
+        let mut v = v;
+        if v >= TWO_POW_64 {
+            match self.overflow {
+                OverflowPolicy::Error => return Err(E::custom("duration overflow")),
+                OverflowPolicy::Saturate => return Ok(Duration::MAX),
+                OverflowPolicy::Wrap => v %= TWO_POW_64,
+            }
+        }
+        let secs_trunc = v.trunc() as u64;
+        let frac = v - (secs_trunc as f64);
+        // a carry to 1000ms past `u64::MAX` seconds is the remaining overflow case
+        let millis = (frac * 1000.0).round() as u128;
+        self.overflow.fit_duration(secs_trunc as u128 * 1_000_000_000 + millis * 1_000_000).map_err(E::custom)
+    }
+    fn visit_str<E>(self, s: &str) -> Result<Duration, E>
+    where
+        E: de::Error,
+    {
+        // Quoted numbers ("90", "1.5") mean the same as their unquoted form.
+        let t = s.trim();
+        let is_digits = |p: &str| !p.is_empty() && p.bytes().all(|b| b.is_ascii_digit());
+        if is_digits(t) {
+            let v = match (t.parse::<u64>(), self.overflow) {
+                (Ok(v), _) => v,
+                (Err(_), OverflowPolicy::Error) => return Err(E::custom("duration overflow")),
+                (Err(_), OverflowPolicy::Saturate) => return Ok(Duration::MAX),
+                (Err(_), OverflowPolicy::Wrap) => {
+                    t.bytes().fold(0u64, |acc, b| acc.wrapping_mul(10).wrapping_add((b - b'0') as u64))
+                }
+            };
+            return self.visit_u64(v);
+        }
+        if let Some((int, frac)) = t.split_once('.') {
+            if is_digits(int) && is_digits(frac) {
+                let v = t.parse::<f64>().map_err(E::custom)?;
+                return self.visit_f64(v);
+            }
+        }
+        (self.parse)(s).map_err(E::custom)
+    }
+    fn visit_string<E>(self, s: String) -> Result<Duration, E>
+    where
+        E: de::Error,
+    {
+        self.visit_str(&s)
+    }
+    fn visit_char<E>(self, _: char) -> Result<Duration, E>
+    where
+        E: de::Error,
+    {
+        Err(E::custom("duration must be a string or number, not a single char"))
+    }
+    fn visit_bytes<E>(self, b: &[u8]) -> Result<Duration, E>
+    where
+        E: de::Error,
+    {
+        let s = std::str::from_utf8(b).map_err(|_| E::custom("duration bytes are not valid UTF-8"))?;
+        self.visit_str(s)
+    }
+    fn visit_byte_buf<E>(self, b: Vec<u8>) -> Result<Duration, E>
+    where
+        E: de::Error,
+    {
+        self.visit_bytes(&b)
+    }
+}
+
+/// `DurVisitor::float_error` for the modules that take integers and strings only.
+const NO_FLOATS: &str = "float durations are not allowed; use integer seconds or a string like '1m 30s'";
+
+/// [`DurVisitor`] plus one string, compared with surrounding whitespace trimmed, that stands for
+/// `value`: the empty string in [`empty_default`], the sentinel in [`capped`].
+struct AliasVisitor<'a> {
+    alias: &'a str,
+    value: Duration,
+}
+
+impl Visitor<'_> for AliasVisitor<'_> {
+    type Value = Duration;
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        DurVisitor::default().expecting(f)?;
+        match self.alias.trim() {
+            "" => f.write_str(", or an empty string for the default"),
+            alias => write!(f, ", or \"{alias}\""),
+        }
+    }
+    fn visit_u64<E: de::Error>(self, v: u64) -> Result<Duration, E> {
+        DurVisitor::default().visit_u64(v)
+    }
+    fn visit_i64<E: de::Error>(self, v: i64) -> Result<Duration, E> {
+        DurVisitor::default().visit_i64(v)
+    }
+    fn visit_f64<E: de::Error>(self, v: f64) -> Result<Duration, E> {
+        DurVisitor::default().visit_f64(v)
+    }
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<Duration, E> {
+        if v.trim() == self.alias.trim() {
+            Ok(self.value)
+        } else {
+            DurVisitor::default().visit_str(v)
+        }
+    }
+}
 
 /// Flexible deserializer: int (secs), float (secs.millis, rounded), or string tokens (d/h/m/s/ms).
 pub fn deserialize<'de, D>(deserializer: D) -> Result<Duration, D::Error>
 where
     D: Deserializer<'de>,
 {
-    struct DurVisitor;
-    impl Visitor<'_> for DurVisitor {
-        type Value = Duration;
-        fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
-            f.write_str("integer seconds, float seconds.millis, or a string like '1h 23m 45s' / '123s' / '250ms'")
-        }
-        fn visit_u64<E>(self, v: u64) -> Result<Duration, E>
-        where
-            E: de::Error,
-        {
-            Ok(Duration::from_secs(v))
+    deserializer.deserialize_any(DurVisitor::default())
+}
+
+/// [`deserialize`] with a chosen [`OverflowPolicy`] for numbers and strings past `Duration::MAX`.
+/// Integers that do not fit `u64` wrap modulo 2^64 before scaling under [`OverflowPolicy::Wrap`].
+pub fn deserialize_with_policy<'de, D>(deserializer: D, policy: OverflowPolicy) -> Result<Duration, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let parse = match policy {
+        OverflowPolicy::Error => parse_str,
+        OverflowPolicy::Saturate => {
+            |s: &str| parse_str_with(s, &ParseOptions::new().overflow(OverflowPolicy::Saturate))
         }
-        fn visit_i64<E>(self, v: i64) -> Result<Duration, E>
-        where
-            E: de::Error,
-        {
-            if v < 0 {
-                return Err(E::custom("negative duration not allowed"));
+        OverflowPolicy::Wrap => |s: &str| parse_str_with(s, &ParseOptions::new().overflow(OverflowPolicy::Wrap)),
+    };
+    deserializer.deserialize_any(DurVisitor { parse, overflow: policy, ..DurVisitor::default() })
+}
+
+/// [`deserialize`] that only accepts the durations in `allowed` (e.g. poll intervals of 1m, 5m or
+/// 15m); the error lists them. `allowed` is an argument; see
+/// [modules that take arguments](crate#modules-that-take-arguments).
+pub fn deserialize_one_of<'de, D>(deserializer: D, allowed: &[Duration]) -> Result<Duration, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let d = deserialize(deserializer)?;
+    check_one_of(d, allowed).map_err(de::Error::custom)
+}
+
+/// Error produced by the serializers before it is wrapped into `S::Error`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SerializeDurationError {
+    /// The value does not fit the output's integer type.
+    TooLarge,
+    /// A fixed-width output would need `digits` digits, more than its `width`.
+    TooWide { digits: usize, width: usize },
+}
+
+impl fmt::Display for SerializeDurationError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SerializeDurationError::TooLarge => f.write_str("duration too large"),
+            SerializeDurationError::TooWide { digits, width } => {
+                write!(f, "duration needs {digits} digits, more than the width of {width}")
             }
-            Ok(Duration::from_secs(v as u64))
         }
-        fn visit_f64<E>(self, v: f64) -> Result<Duration, E>
-        where
-            E: de::Error,
-        {
-            if !v.is_finite() {
-                return Err(E::custom("non-finite float"));
-            }
-            if v < 0.0 {
-                return Err(E::custom("negative duration not allowed"));
-            }
-            let secs_trunc = v.trunc() as u64;
-            let frac = v - (secs_trunc as f64);
-            let mut millis = (frac * 1000.0).round() as u64;
-            let mut secs = secs_trunc;
-            if millis == 1000 {
-                secs = secs.checked_add(1).ok_or_else(|| E::custom("duration overflow"))?;
-                millis = 0;
+    }
+}
+
+impl std::error::Error for SerializeDurationError {}
+
+/// What to do when a value does not fit its target: `Duration` on input, the output type on output.
+///
+/// The plain functions always behave like [`OverflowPolicy::Error`]; the `*_with_policy` functions
+/// and [`ParseOptions::overflow`] let callers pick one behavior for every numeric path.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum OverflowPolicy {
+    /// Report an error.
+    #[default]
+    Error,
+    /// Clamp to the largest representable value (`Duration::MAX`, `u64::MAX`, ...).
+    Saturate,
+    /// Keep the low-order part: modulo 2^64 for integer outputs, modulo `Duration::MAX` + 1ns for
+    /// durations, modulo 2^53 ms for the `f64` output.
+    Wrap,
+}
+
+impl OverflowPolicy {
+    /// `v` brought into `0..=max`, or `None` under [`OverflowPolicy::Error`].
+    const fn fit(self, v: u128, max: u128) -> Option<u128> {
+        if v <= max {
+            return Some(v);
+        }
+        match self {
+            OverflowPolicy::Error => None,
+            OverflowPolicy::Saturate => Some(max),
+            OverflowPolicy::Wrap => Some(v % (max + 1)),
+        }
+    }
+
+    fn fit_u64(self, v: u128) -> Result<u64, SerializeDurationError> {
+        self.fit(v, u64::MAX as u128).map(|v| v as u64).ok_or(SerializeDurationError::TooLarge)
+    }
+
+    fn fit_duration(self, nanos: u128) -> Result<Duration, OverflowError> {
+        self.fit(nanos, MAX_NANOS).and_then(duration_from_nanos).ok_or(OverflowError)
+    }
+}
+
+/// `2^64` as `f64`: floats at or above it are past `u64::MAX` seconds.
+const TWO_POW_64: f64 = 18_446_744_073_709_551_616.0;
+
+/// Largest millisecond count an `f64` still holds exactly.
+const MAX_EXACT_F64_MILLIS: u128 = 1 << 53;
+
+/// Funnel for the string serializers whose formatter can fail (a fixed width, an integer range):
+/// formatting errors become `S::Error` instead of panics. Infallible formatters write directly.
+fn serialize_text<S>(serializer: S, text: Result<String, SerializeDurationError>) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    serializer.serialize_str(&text.map_err(serde::ser::Error::custom)?)
+}
+
+/// Root `serialize`: human format (so `#[serde(with = "serde_ext_duration")]` works).
+pub fn serialize<S>(dur: &Duration, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    serialize_human(dur, serializer)
+}
+
+/// Serialize as the [`to_human_string`] form.
+///
+/// ```
+/// #[derive(serde::Serialize)]
+/// struct Job {
+///     #[serde(serialize_with = "serde_ext_duration::serialize_human")]
+///     timeout: std::time::Duration,
+/// }
+///
+/// let job = Job { timeout: std::time::Duration::from_secs(90 * 60 + 1) };
+/// assert_eq!(serde_json::to_string(&job).unwrap(), r#"{"timeout":"1h 30m 1s"}"#);
+/// ```
+pub fn serialize_human<S>(dur: &Duration, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    serializer.serialize_str(&to_human_string(dur))
+}
+
+pub fn serialize_secs<S>(dur: &Duration, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    serializer.serialize_u64(dur.as_secs())
+}
+
+pub fn serialize_millis<S>(dur: &Duration, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    serializer.serialize_u64(rounded_millis_u64(dur).map_err(serde::ser::Error::custom)?)
+}
+
+/// Milliseconds rounded to nearest (half-up).
+fn rounded_millis(dur: &Duration) -> u128 {
+    (dur.as_secs() as u128) * 1000 + ((dur.subsec_nanos() as u128 + 500_000) / 1_000_000)
+}
+
+/// Milliseconds rounded to nearest, as the `u64` the `millis` serializer emits.
+fn rounded_millis_u64(dur: &Duration) -> Result<u64, SerializeDurationError> {
+    u64::try_from(rounded_millis(dur)).map_err(|_| SerializeDurationError::TooLarge)
+}
+
+/// Like [`serialize_millis`] but truncates sub-millisecond nanos instead of rounding.
+pub fn serialize_millis_floor<S>(dur: &Duration, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    let ms = u64::try_from(dur.as_millis()).map_err(|_| serde::ser::Error::custom(SerializeDurationError::TooLarge))?;
+    serializer.serialize_u64(ms)
+}
+
+pub fn serialize_secs_f64_ms<S>(dur: &Duration, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    let f = dur.as_secs() as f64 + (dur.subsec_millis() as f64) / 1000.0;
+    let f = (f * 1000.0).round() / 1000.0; // 3 decimals
+    serializer.serialize_f64(f)
+}
+
+/// Seconds as `f64`, rounded half-up to exactly `digits` decimals (at most 9).
+///
+/// The float is the nearest one to that decimal, so shortest-round-trip writers such as
+/// `serde_json` print `1.234`, never `1.2340000000000002`. Wrap it for `serialize_with`:
+///
+/// ```
+/// # use std::time::Duration;
+/// fn micros<S: serde::Serializer>(d: &Duration, s: S) -> Result<S::Ok, S::Error> {
+///     serde_ext_duration::serialize_secs_f64_with_digits(d, 6, s)
+/// }
+///
+/// #[derive(serde::Serialize)]
+/// struct Out {
+///     #[serde(serialize_with = "micros")]
+///     t: Duration,
+/// }
+/// # let json = serde_json::to_string(&Out { t: Duration::new(1, 234_567_890) }).unwrap();
+/// # assert_eq!(json, r#"{"t":1.234568}"#);
+/// ```
+///
+/// # Panics
+/// If `digits` is above 9.
+pub fn serialize_secs_f64_with_digits<S>(dur: &Duration, digits: usize, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    assert!(digits <= 9, "at most 9 decimal digits, got {digits}");
+    let step = 10u128.pow(9 - digits as u32);
+    let units = (dur.as_nanos() + step / 2) / step;
+    let scale = 10u128.pow(digits as u32);
+    // Going through the decimal text gives the correctly rounded float, unlike scaling in `f64`.
+    let text = format!("{}.{:0digits$}", units / scale, units % scale);
+    serializer.serialize_f64(text.parse().map_err(serde::ser::Error::custom)?)
+}
+
+/// [`serialize_millis`] with a chosen [`OverflowPolicy`] past `u64::MAX` milliseconds.
+pub fn serialize_millis_with_policy<S>(dur: &Duration, policy: OverflowPolicy, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    serializer.serialize_u64(policy.fit_u64(rounded_millis(dur)).map_err(serde::ser::Error::custom)?)
+}
+
+/// [`nanos_in::serialize`] with a chosen [`OverflowPolicy`] past `u64::MAX` nanoseconds (~584 years).
+pub fn serialize_nanos_with_policy<S>(dur: &Duration, policy: OverflowPolicy, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    serializer.serialize_u64(policy.fit_u64(dur.as_nanos()).map_err(serde::ser::Error::custom)?)
+}
+
+/// [`serialize_secs_f64_ms`] where the policy applies once the millisecond count passes 2^53, the
+/// point at which [`serialize_secs_f64_ms`] silently starts losing precision.
+pub fn serialize_secs_f64_ms_with_policy<S>(
+    dur: &Duration,
+    policy: OverflowPolicy,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    let ms = dur.as_secs() as u128 * 1000 + dur.subsec_millis() as u128;
+    let ms = match policy {
+        // `fit` would wrap modulo `max + 1`; this output wraps modulo 2^53 itself
+        OverflowPolicy::Wrap if ms > MAX_EXACT_F64_MILLIS => ms % MAX_EXACT_F64_MILLIS,
+        _ => policy
+            .fit(ms, MAX_EXACT_F64_MILLIS)
+            .ok_or_else(|| serde::ser::Error::custom(SerializeDurationError::TooLarge))?,
+    };
+    serializer.serialize_f64(ms as f64 / 1000.0)
+}
+
+/// Output units for the single-unit serializers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Unit {
+    Days,
+    Hours,
+    Minutes,
+    Seconds,
+    Millis,
+}
+
+impl Unit {
+    /// Nanoseconds in one of this unit.
+    pub const fn nanos(self) -> u128 {
+        match self {
+            Unit::Days => 86_400_000_000_000,
+            Unit::Hours => 3_600_000_000_000,
+            Unit::Minutes => 60_000_000_000,
+            Unit::Seconds => 1_000_000_000,
+            Unit::Millis => 1_000_000,
+        }
+    }
+
+    /// Suffix used in strings (`d`, `h`, `m`, `s`, `ms`).
+    pub const fn suffix(self) -> &'static str {
+        match self {
+            Unit::Days => "d",
+            Unit::Hours => "h",
+            Unit::Minutes => "m",
+            Unit::Seconds => "s",
+            Unit::Millis => "ms",
+        }
+    }
+
+    /// Plural English name (`days`, `hours`, `minutes`, `seconds`, `milliseconds`).
+    pub const fn long_name(self) -> &'static str {
+        match self {
+            Unit::Days => "days",
+            Unit::Hours => "hours",
+            Unit::Minutes => "minutes",
+            Unit::Seconds => "seconds",
+            Unit::Millis => "milliseconds",
+        }
+    }
+}
+
+/// Largest nanosecond count that still fits in a `Duration`.
+const MAX_NANOS: u128 = (u64::MAX as u128) * 1_000_000_000 + 999_999_999;
+
+/// `Duration` from a nanosecond count, or `None` past `Duration::MAX`.
+fn duration_from_nanos(n: u128) -> Option<Duration> {
+    let secs = u64::try_from(n / 1_000_000_000).ok()?;
+    Some(Duration::new(secs, (n % 1_000_000_000) as u32))
+}
+
+/// Single-unit string such as `"90m"`: rounded half-up to a whole `unit`, capped so it parses back.
+fn to_unit_string(dur: &Duration, unit: Unit) -> String {
+    let n = ((dur.as_nanos() + unit.nanos() / 2) / unit.nanos()).min(MAX_NANOS / unit.nanos());
+    format!("{n}{}", unit.suffix())
+}
+
+/// Serialize as one whole `unit` (e.g. always minutes: `"90m"`, `"1440m"`), rounding half-up.
+pub fn serialize_in_unit<S>(dur: &Duration, unit: Unit, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    serializer.serialize_str(&to_unit_string(dur, unit))
+}
+
+/// Coarsest unit that represents `dur` (rounded to the nearest ms) as a whole number.
+///
+/// `3600s` → `(1, Unit::Hours)`, `90s` → `(90, Unit::Seconds)`, `1500ms` → `(1500, Unit::Millis)`.
+/// Zero divides every unit, so it reports `(0, Unit::Days)`.
+pub fn auto_unit(dur: &Duration) -> (u128, Unit) {
+    let ms_total = rounded_millis(dur).min(MAX_MILLIS);
+    for unit in HUMAN_UNITS {
+        let unit_ms = unit.nanos() / 1_000_000;
+        let (value, rem) = (ms_total / unit_ms, ms_total % unit_ms);
+        if rem == 0 {
+            return (value, unit);
+        }
+    }
+    unreachable!("every count is a whole number of milliseconds")
+}
+
+/// Largest whole-millisecond count that still fits in a `Duration`.
+const MAX_MILLIS: u128 = (u64::MAX as u128) * 1000 + 999;
+
+/// Units emitted by the human format, largest first.
+const HUMAN_UNITS: [Unit; 5] = [Unit::Days, Unit::Hours, Unit::Minutes, Unit::Seconds, Unit::Millis];
+
+/// Build a canonical human string out of a `Duration` with units d/h/m/s/ms.
+///
+/// Grammar: `Nd Nh Nm Ns Nms`, largest unit first, single spaces, zero components skipped, rounded
+/// to the nearest millisecond; zero is `"0s"`.
+///
+/// ```
+/// use serde_ext_duration::to_human_string;
+/// use std::time::Duration;
+///
+/// assert_eq!(to_human_string(&Duration::ZERO), "0s");
+/// assert_eq!(to_human_string(&Duration::from_millis(250)), "250ms");
+/// assert_eq!(to_human_string(&Duration::from_secs(3600)), "1h");
+/// assert_eq!(to_human_string(&Duration::from_millis(3_723_250)), "1h 2m 3s 250ms");
+/// assert_eq!(to_human_string(&Duration::from_secs(86_400 + 5)), "1d 5s");
+/// assert_eq!(to_human_string(&Duration::from_micros(1_999_500)), "2s");
+/// ```
+pub fn to_human_string(dur: &Duration) -> String {
+    HumanFormat::new().format(dur)
+}
+
+/// How sub-millisecond remainders are resolved before formatting.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Rounding {
+    /// Round to the nearest millisecond, half-up (`0.5ms` → `1ms`).
+    #[default]
+    Nearest,
+    /// Truncate (`0.9ms` → `0ms`), so values never round up into a higher bucket.
+    Floor,
+}
+
+/// Whole milliseconds in `dur` under `rounding`.
+fn millis_with(dur: &Duration, rounding: Rounding) -> u128 {
+    match rounding {
+        Rounding::Nearest => rounded_millis(dur),
+        Rounding::Floor => dur.as_millis(),
+    }
+}
+
+/// Configurable human formatter; `HumanFormat::new()` produces exactly the `human` output.
+///
+/// Use [`HumanFormat::serialize`] from a small `serialize_with` function to pick options per field.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HumanFormat {
+    rounding: Rounding,
+    pad_from: Option<Unit>,
+    sub_second_decimal: bool,
+    significant_units: usize,
+    separator: &'static str,
+    unit_separator: &'static str,
+    days_threshold: u64,
+    units: Option<&'static [Unit]>,
+    drop_subsecond_above: Option<Duration>,
+}
+
+impl Default for HumanFormat {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl HumanFormat {
+    pub const fn new() -> Self {
+        HumanFormat {
+            rounding: Rounding::Nearest,
+            pad_from: None,
+            sub_second_decimal: false,
+            significant_units: 0,
+            separator: " ",
+            unit_separator: "",
+            days_threshold: 1,
+            units: None,
+            drop_subsecond_above: None,
+        }
+    }
+
+    /// Sub-millisecond handling (default [`Rounding::Nearest`]).
+    pub const fn rounding(mut self, rounding: Rounding) -> Self {
+        self.rounding = rounding;
+        self
+    }
+
+    /// Always start at `unit`, writing zeros down to the first non-zero component: with
+    /// [`Unit::Hours`], 5 minutes is `"0h 5m"` and zero is `"0h"`. Larger components still appear
+    /// (`"1d 2h"`). Default: no padding.
+    pub const fn pad_from(mut self, unit: Unit) -> Self {
+        self.pad_from = Some(unit);
+        self
+    }
+
+    /// Render values under one second as decimal seconds (`"0.25s"`) instead of `"250ms"`.
+    /// Default: off. [`parse_str`] reads this output back.
+    pub const fn sub_second_as_decimal_seconds(mut self, enabled: bool) -> Self {
+        self.sub_second_decimal = enabled;
+        self
+    }
+
+    /// Approximate to `n` units counted down from the largest non-zero one, rounding half-up on
+    /// what is dropped: with 1, `1h 35m` is `"2h"`; with 2, `1h 3m 17s` is `"1h 3m"` and
+    /// `1d 5s` is `"1d"` (hours are the second unit). `0`, the default, keeps every unit.
+    pub const fn significant_units(mut self, n: usize) -> Self {
+        self.significant_units = n;
+        self
+    }
+
+    /// Text between components (default `" "`): `"\u{a0}"` keeps `"1h 30m"` on one line, `""`
+    /// gives `"1h30m"`.
+    pub const fn separator(mut self, sep: &'static str) -> Self {
+        self.separator = sep;
+        self
+    }
+
+    /// Text between a number and its unit (default `""`): `" "` gives `"1 h 30 m"`, which
+    /// [`parse_str`] still reads back. A non-breaking space is not accepted by the parser.
+    pub const fn unit_separator(mut self, sep: &'static str) -> Self {
+        self.unit_separator = sep;
+        self
+    }
+
+    /// Use `d` only from `n` whole days on; shorter values keep their days in the hours, so with
+    /// 7, two days is `"48h"` and a week is `"7d"`. Default 1, where a day is always `"1d"` (0
+    /// behaves the same).
+    pub const fn days_threshold(mut self, n: u64) -> Self {
+        self.days_threshold = n;
+        self
+    }
+
+    /// Emit only `units`, in the given order: `&[Unit::Minutes, Unit::Seconds]` writes 1h 30m 30s
+    /// as `"90m 30s"`, and `&[Unit::Millis, Unit::Seconds]` writes `"250ms 5s"`. Larger unlisted
+    /// units roll into the next listed one down; what is below the smallest listed unit rounds
+    /// half-up into it. An empty list means the default, every unit from `d` down to `ms`.
+    pub const fn units(mut self, units: &'static [Unit]) -> Self {
+        self.units = Some(units);
+        self
+    }
+
+    /// Truncate the milliseconds of values longer than `threshold`: with one minute,
+    /// `"2d 3h 4m 5s 250ms"` becomes `"2d 3h 4m 5s"` while `"5s 250ms"` is kept. This is lossy, so
+    /// such output no longer parses back to the exact value. Default: never drop them.
+    pub const fn drop_subsecond_above(mut self, threshold: Duration) -> Self {
+        self.drop_subsecond_above = Some(threshold);
+        self
+    }
+
+    /// Fixed columns from `top` down to `floor`: `top` is always written, even when zero, larger
+    /// units roll into it and what is below `floor` rounds half-up into it. With hours and
+    /// seconds, 5 minutes is `"0h 5m"` and 1d 2h is `"26h"`. Shorthand for [`units`](Self::units)
+    /// with that span plus [`pad_from`](Self::pad_from)`(top)`.
+    ///
+    /// # Panics
+    /// If `floor` is larger than `top`.
+    pub fn fixed_top_unit(self, top: Unit, floor: Unit) -> Self {
+        static SPAN: [Unit; 5] = HUMAN_UNITS;
+        let at = |u: Unit| SPAN.iter().position(|&s| s == u).unwrap_or(0);
+        assert!(floor.nanos() <= top.nanos(), "floor unit {floor:?} is larger than the top unit {top:?}");
+        self.units(&SPAN[at(top)..=at(floor)]).pad_from(top)
+    }
+
+    pub fn format(&self, dur: &Duration) -> String {
+        // Resolve to whole milliseconds, then decompose. Rounding never goes past `MAX_MILLIS`, so
+        // the output of `Duration::MAX` still parses back (as `Duration::MAX` truncated to whole ms).
+        let mut ms_total = millis_with(dur, self.rounding).min(MAX_MILLIS);
+        if matches!(self.drop_subsecond_above, Some(t) if *dur > t) {
+            ms_total -= ms_total % 1000;
+        }
+        let listed = |u: &Unit| match self.units {
+            Some(l) if !l.is_empty() => l.contains(u),
+            _ => true,
+        };
+        // Listed units, largest first, in a fixed array to keep this allocation-free.
+        let (mut buf, mut len) = (HUMAN_UNITS, 0);
+        for u in HUMAN_UNITS.iter().filter(|u| listed(u)) {
+            buf[len] = *u;
+            len += 1;
+        }
+        let day_ms = Unit::Days.nanos() / 1_000_000;
+        let roll_days = self.days_threshold > 1 && ms_total / day_ms < self.days_threshold as u128;
+        let skip = usize::from(roll_days && len > 1 && buf[0] == Unit::Days);
+        let units = &buf[skip..len];
+        let smallest = units[units.len() - 1];
+        let pad_from = self.pad_from.map(|p| *units.iter().find(|u| u.nanos() <= p.nanos()).unwrap_or(&smallest));
+        let mut step = smallest.nanos() / 1_000_000;
+        if self.significant_units > 0 && ms_total > 0 {
+            let top = units.iter().position(|u| ms_total >= u.nanos() / 1_000_000).unwrap_or(0);
+            let last = units[top.saturating_add(self.significant_units - 1).min(units.len() - 1)];
+            step = step.max(last.nanos() / 1_000_000);
+        }
+        if step > 1 {
+            let (down, rem) = (ms_total - ms_total % step, ms_total % step);
+            ms_total = if rem * 2 >= step && down + step <= MAX_MILLIS { down + step } else { down };
+        }
+
+        if ms_total == 0 {
+            let unit = pad_from.unwrap_or(if units.contains(&Unit::Seconds) { Unit::Seconds } else { smallest });
+            return format!("0{}{}", self.unit_separator, unit.suffix());
+        }
+        if self.sub_second_decimal && ms_total < 1000 && units.contains(&Unit::Seconds) {
+            let frac = format!("{ms_total:03}");
+            return format!("0.{}{}s", frac.trim_end_matches('0'), self.unit_separator);
+        }
+
+        let (mut parts, mut count) = ([(Unit::Days, 0); 5], 0);
+        let mut padding = false;
+        for &unit in units {
+            let unit_ms = unit.nanos() / 1_000_000;
+            let n = ms_total / unit_ms;
+            ms_total %= unit_ms;
+            padding |= count == 0 && pad_from == Some(unit);
+            if n > 0 || padding {
+                padding = n == 0;
+                parts[count] = (unit, n);
+                count += 1;
             }
-            Duration::from_secs(secs)
-                .checked_add(Duration::from_millis(millis))
-                .ok_or_else(|| E::custom("duration overflow"))
         }
-        fn visit_str<E>(self, s: &str) -> Result<Duration, E>
-        where
-            E: de::Error,
-        {
-            parse_str(s).map_err(E::custom)
+        if let Some(order) = self.units {
+            parts[..count].sort_by_key(|(u, _)| order.iter().position(|o| o == u));
         }
-        fn visit_string<E>(self, s: String) -> Result<Duration, E>
-        where
-            E: de::Error,
-        {
-            self.visit_str(&s)
+        let mut out = String::new();
+        for &(unit, n) in &parts[..count] {
+            if !out.is_empty() {
+                out.push_str(self.separator);
+            }
+            let _ = write!(out, "{n}{}{}", self.unit_separator, unit.suffix());
         }
+        out
+    }
+
+    pub fn serialize<S>(&self, dur: &Duration, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(&self.format(dur))
+    }
+}
+
+/// Human: `serialize` + flexible `deserialize`.
+pub mod human {
+    use super::*;
+    pub fn serialize<S>(d: &Duration, s: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        super::serialize_human(d, s)
+    }
+    pub fn deserialize<'de, D>(d: D) -> Result<Duration, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        super::deserialize(d)
+    }
+}
+
+/// Seconds (u64) on output; flexible input on deserialize.
+pub mod secs {
+    use super::*;
+    pub fn serialize<S>(d: &Duration, s: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        super::serialize_secs(d, s)
+    }
+    pub fn deserialize<'de, D>(d: D) -> Result<Duration, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        super::deserialize(d)
+    }
+}
+
+/// Milliseconds (u64) on output; flexible input on deserialize.
+pub mod millis {
+    use super::*;
+    pub fn serialize<S>(d: &Duration, s: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        super::serialize_millis(d, s)
+    }
+    pub fn deserialize<'de, D>(d: D) -> Result<Duration, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        super::deserialize(d)
+    }
+}
+
+/// Seconds as f64 (ms precision) on output; flexible input on deserialize.
+pub mod secs_f64_ms {
+    use super::*;
+    pub fn serialize<S>(d: &Duration, s: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        super::serialize_secs_f64_ms(d, s)
+    }
+    pub fn deserialize<'de, D>(d: D) -> Result<Duration, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        super::deserialize(d)
+    }
+}
+
+/// Milliseconds (u64, truncated) on output; flexible input on deserialize.
+pub mod millis_floor {
+    use super::*;
+    pub fn serialize<S>(d: &Duration, s: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        super::serialize_millis_floor(d, s)
+    }
+    pub fn deserialize<'de, D>(d: D) -> Result<Duration, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        super::deserialize(d)
+    }
+}
+
+/// Human output with sub-millisecond nanos truncated; flexible input on deserialize.
+pub mod human_floor {
+    use super::*;
+    pub fn serialize<S>(d: &Duration, s: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        HumanFormat::new().rounding(Rounding::Floor).serialize(d, s)
+    }
+    pub fn deserialize<'de, D>(d: D) -> Result<Duration, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        super::deserialize(d)
+    }
+}
+
+/// Whole days (`"3d"`) on output; flexible input on deserialize.
+pub mod in_days {
+    use super::*;
+    pub fn serialize<S>(d: &Duration, s: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        super::serialize_in_unit(d, Unit::Days, s)
+    }
+    pub fn deserialize<'de, D>(d: D) -> Result<Duration, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        super::deserialize(d)
+    }
+}
+
+/// Whole hours (`"36h"`) on output; flexible input on deserialize.
+pub mod in_hours {
+    use super::*;
+    pub fn serialize<S>(d: &Duration, s: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        super::serialize_in_unit(d, Unit::Hours, s)
+    }
+    pub fn deserialize<'de, D>(d: D) -> Result<Duration, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        super::deserialize(d)
+    }
+}
+
+/// Whole minutes (`"90m"`) on output; flexible input on deserialize.
+pub mod in_minutes {
+    use super::*;
+    pub fn serialize<S>(d: &Duration, s: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        super::serialize_in_unit(d, Unit::Minutes, s)
+    }
+    pub fn deserialize<'de, D>(d: D) -> Result<Duration, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        super::deserialize(d)
+    }
+}
+
+/// Whole seconds (`"5400s"`) on output; flexible input on deserialize.
+pub mod in_seconds {
+    use super::*;
+    pub fn serialize<S>(d: &Duration, s: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        super::serialize_in_unit(d, Unit::Seconds, s)
+    }
+    pub fn deserialize<'de, D>(d: D) -> Result<Duration, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        super::deserialize(d)
+    }
+}
+
+/// Whole milliseconds (`"1500ms"`) on output; flexible input on deserialize.
+pub mod in_millis {
+    use super::*;
+    pub fn serialize<S>(d: &Duration, s: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        super::serialize_in_unit(d, Unit::Millis, s)
+    }
+    pub fn deserialize<'de, D>(d: D) -> Result<Duration, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        super::deserialize(d)
+    }
+}
+
+/// Integer nanoseconds (u64) on output; bare integers are **nanoseconds** on input.
+///
+/// Floats and strings keep their flexible meaning (float seconds, unit tokens).
+pub mod nanos_in {
+    use super::*;
+    pub fn serialize<S>(d: &Duration, s: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let n = u64::try_from(d.as_nanos()).map_err(|_| serde::ser::Error::custom(SerializeDurationError::TooLarge))?;
+        s.serialize_u64(n)
+    }
+    pub fn deserialize<'de, D>(d: D) -> Result<Duration, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        d.deserialize_any(DurVisitor { int_nanos: 1, ..DurVisitor::default() })
+    }
+}
+
+/// Integer centiseconds (u64, 1/100 s) on output; bare integers are **centiseconds** on input.
+///
+/// Output rounds to the nearest centisecond, half-up (`5ms` → `1`, `4.999ms` → `0`). Floats and
+/// strings keep their flexible meaning (float seconds, unit tokens).
+pub mod centis {
+    use super::*;
+
+    const NANOS_PER_CENTI: u128 = 10_000_000;
+
+    pub fn serialize<S>(d: &Duration, s: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let n = (d.as_nanos() + NANOS_PER_CENTI / 2) / NANOS_PER_CENTI;
+        let n = u64::try_from(n).map_err(|_| serde::ser::Error::custom(SerializeDurationError::TooLarge))?;
+        s.serialize_u64(n)
+    }
+    pub fn deserialize<'de, D>(d: D) -> Result<Duration, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        d.deserialize_any(DurVisitor { int_nanos: NANOS_PER_CENTI, ..DurVisitor::default() })
+    }
+}
+
+/// Integer in the coarsest evenly-dividing unit (see [`auto_unit`](fn@crate::auto_unit)).
+///
+/// The unit is not part of the output: report it next to the value with the `auto_unit` function.
+/// Serialize-only, use it as `#[serde(serialize_with = "serde_ext_duration::auto_unit::serialize")]`.
+pub mod auto_unit {
+    use super::*;
+    pub fn serialize<S>(d: &Duration, s: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let (value, _) = super::auto_unit(d);
+        s.serialize_u64(u64::try_from(value).map_err(|_| serde::ser::Error::custom(SerializeDurationError::TooLarge))?)
+    }
+}
+
+/// Human output; input is a duration string or integer seconds, but **never a float**.
+///
+/// For configs that allow numeric seconds for backward compatibility yet want to rule out
+/// ambiguous values like `1.5`.
+pub mod human_or_secs {
+    use super::*;
+    pub fn serialize<S>(d: &Duration, s: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        super::serialize_human(d, s)
+    }
+    pub fn deserialize<'de, D>(d: D) -> Result<Duration, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        d.deserialize_any(DurVisitor { float_error: Some(NO_FLOATS), ..DurVisitor::default() })
+    }
+}
+
+/// Video frame counts at a given FPS (may be fractional, e.g. `29.97`).
+///
+/// Frames are integers on the wire. Durations that are not a whole number of frames round to the
+/// nearest frame, and frame counts convert to the nearest nanosecond. The FPS is an argument; see
+/// [modules that take arguments](crate#modules-that-take-arguments).
+pub mod frames {
+    use super::*;
+
+    fn check_fps(fps: f64) {
+        assert!(fps.is_finite() && fps > 0.0, "fps must be finite and positive, got {fps}");
+    }
+
+    /// Duration of `count` frames at `fps`, to the nearest nanosecond.
+    ///
+    /// # Panics
+    /// If `fps` is not finite and positive, or the result exceeds `Duration::MAX`.
+    pub fn from_frames(count: u64, fps: f64) -> Duration {
+        try_from_frames(count, fps).expect("frame count overflows Duration")
+    }
+
+    /// [`from_frames`], or `None` when the result exceeds `Duration::MAX`.
+    ///
+    /// # Panics
+    /// If `fps` is not finite and positive.
+    pub fn try_from_frames(count: u64, fps: f64) -> Option<Duration> {
+        check_fps(fps);
+        let nanos = (count as f64 * 1e9 / fps).round();
+        if nanos > MAX_NANOS as f64 {
+            return None;
+        }
+        duration_from_nanos(nanos as u128)
+    }
+
+    /// Number of frames in `dur` at `fps`, rounded to the nearest frame (saturating at `u64::MAX`).
+    ///
+    /// # Panics
+    /// If `fps` is not finite and positive.
+    pub fn to_frames(dur: &Duration, fps: f64) -> u64 {
+        check_fps(fps);
+        (dur.as_secs_f64() * fps).round() as u64
+    }
+
+    pub fn serialize<S>(d: &Duration, fps: f64, s: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        s.serialize_u64(to_frames(d, fps))
+    }
+
+    pub fn deserialize<'de, D>(d: D, fps: f64) -> Result<Duration, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let count = u64::deserialize(d)?;
+        try_from_frames(count, fps).ok_or_else(|| de::Error::custom("duration overflow"))
+    }
+}
+
+/// Beat counts at a given tempo in BPM, e.g. "4 beats at 120 BPM" is two seconds.
+///
+/// Beats may be integers or fractions on input and convert to the nearest nanosecond. Output is
+/// the beat count rounded to a thousandth of a beat, written as an integer when whole. The tempo is
+/// an argument; see [modules that take arguments](crate#modules-that-take-arguments).
+pub mod beats {
+    use super::*;
+
+    fn check_bpm(bpm: f64) {
+        assert!(bpm.is_finite() && bpm > 0.0, "bpm must be finite and positive, got {bpm}");
+    }
+
+    fn try_from_beats(beats: f64, bpm: f64) -> Result<Duration, ScaleError> {
+        check_bpm(bpm);
+        scale(Duration::from_secs(60), beats / bpm)
+    }
+
+    /// Duration of `beats` beats at `bpm`, to the nearest nanosecond.
+    ///
+    /// # Panics
+    /// If `bpm` is not finite and positive, `beats` is negative or not finite, or the result
+    /// exceeds `Duration::MAX`.
+    pub fn from_beats(beats: f64, bpm: f64) -> Duration {
+        try_from_beats(beats, bpm).unwrap_or_else(|e| panic!("{beats} beats at {bpm} bpm: {e}"))
+    }
+
+    /// Number of beats in `dur` at `bpm`.
+    ///
+    /// # Panics
+    /// If `bpm` is not finite and positive.
+    pub fn to_beats(dur: &Duration, bpm: f64) -> f64 {
+        check_bpm(bpm);
+        dur.as_secs_f64() * bpm / 60.0
+    }
+
+    pub fn serialize<S>(d: &Duration, bpm: f64, s: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let beats = (to_beats(d, bpm) * 1000.0).round() / 1000.0;
+        if beats.fract() == 0.0 && beats < TWO_POW_64 {
+            s.serialize_u64(beats as u64)
+        } else {
+            s.serialize_f64(beats)
+        }
+    }
+
+    /// # Panics
+    /// If `bpm` is not finite and positive; bad beat counts are ordinary errors.
+    pub fn deserialize<'de, D>(d: D, bpm: f64) -> Result<Duration, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let beats = f64::deserialize(d)?;
+        try_from_beats(beats, bpm).map_err(|e| match e {
+            ScaleError::InvalidFactor => de::Error::custom("beat count must be finite and non-negative"),
+            ScaleError::Overflow => de::Error::custom(e),
+        })
+    }
+}
+
+/// `Vec<Duration>` that must hold at least one entry (e.g. a retry schedule).
+///
+/// Each element is parsed flexibly and serialized as human; an empty list is rejected.
+pub mod non_empty_vec {
+    use super::*;
+    pub fn serialize<S>(v: &[Duration], s: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        s.collect_seq(v.iter().map(|d| ExtDuration(*d)))
+    }
+    pub fn deserialize<'de, D>(d: D) -> Result<Vec<Duration>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let v = Vec::<ExtDuration>::deserialize(d)?;
+        if v.is_empty() {
+            return Err(de::Error::custom("expected at least one duration"));
+        }
+        Ok(v.into_iter().map(|ExtDuration(d)| d).collect())
+    }
+}
+
+/// Human output after [`round_to`] a granularity; flexible input on deserialize.
+///
+/// Pass the granularity from a small wrapper module, as shown for [`frames`].
+pub mod rounded {
+    use super::*;
+    pub fn serialize<S>(d: &Duration, granularity: Duration, s: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        super::serialize_human(&round_to(*d, granularity), s)
+    }
+    pub fn deserialize<'de, D>(d: D) -> Result<Duration, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        super::deserialize(d)
+    }
+}
+
+/// Maps keyed by `Duration` (`HashMap`, `BTreeMap`, ...): human-string keys out, flexible keys in.
+///
+/// `{"30s": "fast", "5m": "slow"}` ↔ `{30s => "fast", 300s => "slow"}`. Keys that parse to the same
+/// duration (`"60s"` and `"1m"`) collapse into one entry, the later value winning. Alternatively,
+/// use [`ExtDuration`] as the key type directly without any attribute.
+pub mod map_keys {
+    use std::marker::PhantomData;
+
+    use super::*;
+
+    pub fn serialize<'a, M, V, S>(map: &'a M, s: S) -> Result<S::Ok, S::Error>
+    where
+        &'a M: IntoIterator<Item = (&'a Duration, &'a V)>,
+        V: Serialize + 'a,
+        S: Serializer,
+    {
+        s.collect_map(map.into_iter().map(|(k, v)| (ExtDuration(*k), v)))
+    }
+
+    pub fn deserialize<'de, M, V, D>(d: D) -> Result<M, D::Error>
+    where
+        M: Default + Extend<(Duration, V)>,
+        V: Deserialize<'de>,
+        D: Deserializer<'de>,
+    {
+        struct MapVisitor<M, V>(PhantomData<(M, V)>);
+        impl<'de, M, V> Visitor<'de> for MapVisitor<M, V>
+        where
+            M: Default + Extend<(Duration, V)>,
+            V: Deserialize<'de>,
+        {
+            type Value = M;
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("a map keyed by durations")
+            }
+            fn visit_map<A>(self, mut access: A) -> Result<M, A::Error>
+            where
+                A: de::MapAccess<'de>,
+            {
+                let mut out = M::default();
+                while let Some((ExtDuration(k), v)) = access.next_entry::<ExtDuration, V>()? {
+                    out.extend(Some((k, v)));
+                }
+                Ok(out)
+            }
+        }
+        d.deserialize_map(MapVisitor(PhantomData))
+    }
+}
+
+/// Gauge plus label: `{"seconds": 65.0, "human": "1m 5s"}`.
+///
+/// `seconds` has millisecond precision, as in `secs_f64_ms`. On deserialize either field is
+/// enough; when both are present **`seconds` is authoritative** and `human` is ignored. A bare
+/// flexible scalar (`65`, `"1m 5s"`) is accepted too.
+pub mod metric {
+    use serde::ser::SerializeStruct;
+
+    use super::*;
+
+    const FIELDS: &[&str] = &["seconds", "human"];
+
+    pub fn serialize<S>(d: &Duration, s: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let seconds = (rounded_millis(d) as f64) / 1000.0;
+        let mut st = s.serialize_struct("Metric", 2)?;
+        st.serialize_field("seconds", &seconds)?;
+        st.serialize_field("human", &to_human_string(d))?;
+        st.end()
+    }
+
+    pub fn deserialize<'de, D>(d: D) -> Result<Duration, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct MetricVisitor;
+        impl<'de> Visitor<'de> for MetricVisitor {
+            type Value = Duration;
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("a {\"seconds\", \"human\"} object or a flexible duration")
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Duration, A::Error>
+            where
+                A: de::MapAccess<'de>,
+            {
+                let (mut seconds, mut human) = (None, None);
+                while let Some(key) = map.next_key::<String>()? {
+                    let slot = match key.as_str() {
+                        "seconds" => &mut seconds,
+                        "human" => &mut human,
+                        _ => return Err(de::Error::unknown_field(&key, FIELDS)),
+                    };
+                    if slot.is_some() {
+                        return Err(de::Error::custom(format!("duplicate field `{key}`")));
+                    }
+                    *slot = Some(map.next_value::<ExtDuration>()?.0);
+                }
+                seconds.or(human).ok_or_else(|| de::Error::missing_field("seconds"))
+            }
+            fn visit_u64<E: de::Error>(self, v: u64) -> Result<Duration, E> {
+                DurVisitor::default().visit_u64(v)
+            }
+            fn visit_i64<E: de::Error>(self, v: i64) -> Result<Duration, E> {
+                DurVisitor::default().visit_i64(v)
+            }
+            fn visit_f64<E: de::Error>(self, v: f64) -> Result<Duration, E> {
+                DurVisitor::default().visit_f64(v)
+            }
+            fn visit_str<E: de::Error>(self, v: &str) -> Result<Duration, E> {
+                DurVisitor::default().visit_str(v)
+            }
+        }
+        d.deserialize_any(MetricVisitor)
+    }
+}
+
+/// Scheduling phrases: `"every 30m"`, `"every 1h 30m"`.
+///
+/// A leading `every` (any case) is stripped before the rest goes through [`parse_str`]; plain
+/// durations are accepted too. Serializes as `"every <human>"`.
+pub mod schedule {
+    use super::*;
+
+    pub fn parse(s: &str) -> Result<Duration, String> {
+        let t = s.trim_start();
+        match t.get(..5) {
+            Some(word) if word.eq_ignore_ascii_case("every") && t[5..].starts_with(|c: char| c.is_whitespace()) => {
+                parse_str(&t[5..])
+            }
+            _ => parse_str(s),
+        }
+    }
+
+    pub fn serialize<S>(d: &Duration, s: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        s.serialize_str(&format!("every {}", to_human_string(d)))
+    }
+    pub fn deserialize<'de, D>(d: D) -> Result<Duration, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        d.deserialize_any(DurVisitor { parse, ..DurVisitor::default() })
+    }
+}
+
+/// Cron-extension intervals as robfig/cron and similar schedulers take them: `"@every 1h30m"`.
+///
+/// The `@every` prefix is required on input, followed by whitespace and a duration in the
+/// flexible grammar. Output writes the duration the way Go's `time.ParseDuration` reads it: no
+/// spaces and no days, so two days is `"@every 48h"`.
+pub mod cron_every {
+    use super::*;
+
+    const FORMAT: HumanFormat =
+        HumanFormat::new().separator("").units(&[Unit::Hours, Unit::Minutes, Unit::Seconds, Unit::Millis]);
+
+    pub fn to_cron_string(d: &Duration) -> String {
+        format!("@every {}", FORMAT.format(d))
+    }
+
+    pub fn parse(s: &str) -> Result<Duration, String> {
+        let t = s.trim();
+        match t.strip_prefix("@every") {
+            Some(rest) if rest.starts_with(char::is_whitespace) => parse_str(rest),
+            _ => Err(format!("expected '@every <duration>', got '{t}'")),
+        }
+    }
+
+    pub fn serialize<S>(d: &Duration, s: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        s.serialize_str(&to_cron_string(d))
+    }
+    pub fn deserialize<'de, D>(d: D) -> Result<Duration, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct CronVisitor;
+        impl<'de> Visitor<'de> for CronVisitor {
+            type Value = Duration;
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("an '@every <duration>' string")
+            }
+            fn visit_str<E: de::Error>(self, v: &str) -> Result<Duration, E> {
+                parse(v).map_err(E::custom)
+            }
+        }
+        d.deserialize_str(CronVisitor)
+    }
+}
+
+/// SI-prefixed seconds: `"1ks"`, `"2Ms"`, `"500ms"`, `"1.5us"`.
+///
+/// **Different unit semantics than the rest of the crate:** every unit is `s` with an optional SI
+/// prefix (`G`, `M`, `k`, `m`, `u`/`µ`, `n`), matched case-sensitively, so `ms` is milli and `Ms`
+/// is mega. There are no minutes, hours or days. Serializes as a whole number in the largest
+/// prefix that represents the value exactly, e.g. `"1500ms"`, `"3ks"`.
+pub mod si {
+    use super::*;
+
+    /// Prefixed units and their length in nanoseconds, largest first.
+    const UNITS: &[(&str, u128)] = &[
+        ("Gs", 1_000_000_000 * 1_000_000_000),
+        ("Ms", 1_000_000 * 1_000_000_000),
+        ("ks", 1_000 * 1_000_000_000),
+        ("s", 1_000_000_000),
+        ("ms", 1_000_000),
+        ("us", 1_000),
+        ("µs", 1_000),
+        ("ns", 1),
+    ];
+
+    pub fn parse(s: &str) -> Result<Duration, String> {
+        let mut total_nanos: u128 = 0;
+        let mut rest = s.trim_start();
+        if rest.is_empty() {
+            return Err("empty duration string".into());
+        }
+        while !rest.is_empty() {
+            let pos = s.len() - rest.len();
+            let int_len = rest.bytes().take_while(u8::is_ascii_digit).count();
+            if int_len == 0 {
+                return Err(format!("expected number at position {pos}"));
+            }
+            let n: u128 = rest[..int_len].parse().map_err(|_| "duration overflow".to_string())?;
+            rest = &rest[int_len..];
+            let (mut frac, mut scale) = (0u128, 1u128);
+            if let Some(digits) = rest.strip_prefix('.').filter(|r| r.starts_with(|c: char| c.is_ascii_digit())) {
+                let frac_len = digits.bytes().take_while(u8::is_ascii_digit).count();
+                for b in digits.bytes().take(frac_len.min(MAX_FRACTION_DIGITS)) {
+                    frac = frac * 10 + (b - b'0') as u128;
+                    scale *= 10;
+                }
+                rest = &digits[frac_len..];
+            }
+            rest = rest.trim_start();
+            let unit_len = rest.find(|c: char| !c.is_alphabetic()).unwrap_or(rest.len());
+            if unit_len == 0 {
+                return Err(format!("expected unit after number at position {pos}"));
+            }
+            let unit = &rest[..unit_len];
+            let nanos_per_unit = match UNITS.iter().find(|(name, _)| *name == unit) {
+                Some(&(_, nanos)) => nanos,
+                None => return Err(format!("unknown unit '{unit}' (use Gs, Ms, ks, s, ms, us, ns)")),
+            };
+            let inc = n
+                .checked_mul(nanos_per_unit)
+                .and_then(|whole| whole.checked_add((frac * nanos_per_unit + scale / 2) / scale))
+                .ok_or_else(|| "duration overflow".to_string())?;
+            total_nanos = total_nanos.checked_add(inc).ok_or_else(|| "duration overflow".to_string())?;
+            rest = rest[unit_len..].trim_start();
+        }
+        duration_from_nanos(total_nanos).ok_or_else(|| "duration too large".into())
+    }
+
+    /// `"0s"` for zero, otherwise the value in the largest prefix that divides it evenly.
+    pub fn to_si_string(d: &Duration) -> String {
+        let n = d.as_nanos();
+        if n == 0 {
+            return "0s".into();
+        }
+        for &(name, nanos) in UNITS.iter().filter(|(name, _)| *name != "µs") {
+            let (value, rem) = (n / nanos, n % nanos);
+            if rem == 0 {
+                return format!("{value}{name}");
+            }
+        }
+        unreachable!("every value is a whole number of nanoseconds")
+    }
+
+    pub fn serialize<S>(d: &Duration, s: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        s.serialize_str(&to_si_string(d))
+    }
+    pub fn deserialize<'de, D>(d: D) -> Result<Duration, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        d.deserialize_any(DurVisitor { parse, ..DurVisitor::default() })
+    }
+}
+
+/// `[secs, nanos]` pairs, the tuple layout some binary encodings use for `Duration`.
+///
+/// Exactly two integers; nanoseconds of a second or more carry into the seconds. Uses
+/// `deserialize_tuple`, so it also works with non-self-describing formats like bincode.
+pub mod tuple_repr {
+    use serde::ser::SerializeTuple;
+
+    use super::*;
+
+    pub fn serialize<S>(d: &Duration, s: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut t = s.serialize_tuple(2)?;
+        t.serialize_element(&d.as_secs())?;
+        t.serialize_element(&d.subsec_nanos())?;
+        t.end()
+    }
+
+    pub fn deserialize<'de, D>(d: D) -> Result<Duration, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct TupleVisitor;
+        impl<'de> Visitor<'de> for TupleVisitor {
+            type Value = Duration;
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("a [secs, nanos] pair of integers")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Duration, A::Error>
+            where
+                A: de::SeqAccess<'de>,
+            {
+                let secs: u64 = seq.next_element()?.ok_or_else(|| de::Error::invalid_length(0, &self))?;
+                let nanos: u64 = seq.next_element()?.ok_or_else(|| de::Error::invalid_length(1, &self))?;
+                if seq.next_element::<de::IgnoredAny>()?.is_some() {
+                    return Err(de::Error::invalid_length(3, &self));
+                }
+                let total = secs as u128 * Unit::Seconds.nanos() + nanos as u128;
+                duration_from_nanos(total).ok_or_else(|| de::Error::custom("duration overflow"))
+            }
+        }
+        d.deserialize_tuple(2, TupleVisitor)
+    }
+}
+
+/// Empty or whitespace-only strings mean "use `default`", as HTML forms submit them.
+///
+/// Everything else is read like [`deserialize`]; output is human. The default is an argument; see
+/// [modules that take arguments](crate#modules-that-take-arguments).
+pub mod empty_default {
+    use super::*;
+
+    pub fn serialize<S>(d: &Duration, s: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        super::serialize_human(d, s)
+    }
+
+    pub fn deserialize<'de, D>(d: D, default: Duration) -> Result<Duration, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        d.deserialize_any(AliasVisitor { alias: "", value: default })
+    }
+}
+
+/// Human output that stops at `max`: anything longer is written as a fixed `sentinel` such as
+/// `">30d"` or `"∞"`, so near-infinite timeouts don't show up as `"4000000d"` on a dashboard.
+///
+/// `max` itself is still written exactly. On input the sentinel (surrounding whitespace ignored)
+/// reads back as `max`, since the exact value is gone; everything else is read like
+/// [`deserialize`]. `max` and the sentinel are arguments; see
+/// [modules that take arguments](crate#modules-that-take-arguments).
+pub mod capped {
+    use super::*;
+
+    pub fn serialize<S>(d: &Duration, max: Duration, sentinel: &str, s: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        if *d > max {
+            s.serialize_str(sentinel)
+        } else {
+            super::serialize_human(d, s)
+        }
+    }
+
+    pub fn deserialize<'de, D>(d: D, max: Duration, sentinel: &str) -> Result<Duration, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        d.deserialize_any(AliasVisitor { alias: sentinel, value: max })
+    }
+}
+
+/// Sums and differences of durations: `"1h - 30m"`, `"2h - 15m - 15m"`, `"1h + 90s"`.
+///
+/// Each term is a [`parse_str`] duration, evaluated left to right. A negative **final** result is
+/// an error ("duration underflow") rather than clamping to zero, so a misconfigured lead time does
+/// not silently become "now". Serializes as the plain human result.
+pub mod arith {
+    use super::*;
+
+    pub fn parse(s: &str) -> Result<Duration, String> {
+        let mut total: i128 = 0;
+        let mut sign = 1;
+        let mut rest = s;
+        loop {
+            let end = rest.find(&['+', '-'][..]).unwrap_or(rest.len());
+            let term = parse_str(&rest[..end]).map_err(|e| format!("in term '{}': {e}", rest[..end].trim()))?;
+            total = total.checked_add(sign * term.as_nanos() as i128).ok_or_else(|| "duration overflow".to_string())?;
+            match rest[end..].chars().next() {
+                Some(op) => {
+                    sign = if op == '+' { 1 } else { -1 };
+                    rest = &rest[end + 1..];
+                }
+                None => break,
+            }
+        }
+        let nanos = u128::try_from(total).map_err(|_| "duration underflow".to_string())?;
+        duration_from_nanos(nanos).ok_or_else(|| "duration too large".into())
+    }
+
+    pub fn serialize<S>(d: &Duration, s: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        super::serialize_human(d, s)
+    }
+    pub fn deserialize<'de, D>(d: D) -> Result<Duration, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        d.deserialize_any(DurVisitor { parse, ..DurVisitor::default() })
+    }
+}
+
+/// Clock-style durations as spreadsheets export them: `"5:00"` (MM:SS) and `"1:05:00"` (HH:MM:SS).
+///
+/// The number of colons picks the layout; the leading field is unbounded (`"90:00"` is 90 minutes)
+/// and the others must stay below 60. Seconds may carry a fraction (`"0:01.5"`). `DD:HH:MM:SS` is
+/// rejected here and accepted by [`clock::days`]. Serializes as `H:MM:SS`, plus `.mmm` when
+/// there are milliseconds.
+pub mod clock {
+    use super::*;
+
+    pub fn parse(s: &str) -> Result<Duration, String> {
+        parse_fields(s, false)
+    }
+
+    /// `"1:05:00"` for 1h 5m; milliseconds are rounded and appended as `".250"` when non-zero.
+    pub fn to_clock_string(d: &Duration) -> String {
+        let ms = rounded_millis(d).min(MAX_MILLIS);
+        let (secs, sub_ms) = (ms / 1000, ms % 1000);
+        let mut out = format!("{}:{:02}:{:02}", secs / 3600, secs / 60 % 60, secs % 60);
+        if sub_ms > 0 {
+            let _ = write!(out, ".{sub_ms:03}");
+        }
+        out
+    }
+
+    fn parse_fields(s: &str, allow_days: bool) -> Result<Duration, String> {
+        use Unit::*;
+        let fields: Vec<&str> = s.trim().split(':').collect();
+        let (units, limits): (&[Unit], &[u128]) = match fields.len() {
+            2 => (&[Minutes, Seconds], &[0, 60]),
+            3 => (&[Hours, Minutes, Seconds], &[0, 60, 60]),
+            4 if allow_days => (&[Days, Hours, Minutes, Seconds], &[0, 24, 60, 60]),
+            _ if allow_days => return Err("expected MM:SS, HH:MM:SS or DD:HH:MM:SS".into()),
+            _ => return Err("expected MM:SS or HH:MM:SS".into()),
+        };
+        let mut total: u128 = 0;
+        for (i, (field, unit)) in fields.iter().zip(units).enumerate() {
+            let (whole, frac) = match field.split_once('.') {
+                Some((whole, frac)) if *unit == Seconds => (whole, frac),
+                _ => (*field, ""),
+            };
+            let digits = |t: &str| !t.is_empty() && t.bytes().all(|b| b.is_ascii_digit());
+            if !digits(whole) || (field.len() > whole.len() && !digits(frac)) {
+                return Err(format!("invalid clock field '{field}'"));
+            }
+            let n: u128 = whole.parse().map_err(|_| "duration overflow".to_string())?;
+            if limits[i] != 0 && n >= limits[i] {
+                return Err(format!("clock field '{field}' must be below {}", limits[i]));
+            }
+            let frac_nanos =
+                frac.bytes().chain(std::iter::repeat(b'0')).take(9).fold(0, |acc, b| acc * 10 + (b - b'0') as u128);
+            total = n
+                .checked_mul(unit.nanos())
+                .and_then(|v| v.checked_add(frac_nanos))
+                .and_then(|v| v.checked_add(total))
+                .ok_or_else(|| "duration overflow".to_string())?;
+        }
+        duration_from_nanos(total).ok_or_else(|| "duration too large".into())
+    }
+
+    pub fn serialize<S>(d: &Duration, s: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        s.serialize_str(&to_clock_string(d))
+    }
+    pub fn deserialize<'de, D>(d: D) -> Result<Duration, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        d.deserialize_any(DurVisitor { parse, ..DurVisitor::default() })
+    }
+
+    /// [`clock`](super) plus the `DD:HH:MM:SS` layout (hours below 24), e.g. `"1:02:00:00"`.
+    pub mod days {
+        use super::*;
+
+        pub fn parse(s: &str) -> Result<Duration, String> {
+            parse_fields(s, true)
+        }
+
+        pub fn serialize<S>(d: &Duration, s: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            super::serialize(d, s)
+        }
+        pub fn deserialize<'de, D>(d: D) -> Result<Duration, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            d.deserialize_any(DurVisitor { parse, ..DurVisitor::default() })
+        }
+    }
+}
+
+/// HTTP `delta-seconds` (RFC 9111 §1.2.2), e.g. for `Cache-Control: max-age=N` or `Retry-After`.
+///
+/// Whole seconds, truncated like [`secs`]. Values above 2^31 seconds (about 68 years) are clamped to
+/// `2147483648` in both directions, as the RFC asks of senders and caches. Input must be a
+/// non-negative integer or a string of digits; floats and unit strings are rejected.
+pub mod http_delta_seconds {
+    use super::*;
+
+    /// Largest delta-seconds value a cache is required to handle.
+    pub const MAX: u64 = 1 << 31;
+
+    fn parse(s: &str) -> Result<Duration, String> {
+        Err(format!("expected delta-seconds (digits only), got '{s}'"))
+    }
+
+    pub fn serialize<S>(d: &Duration, s: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        s.serialize_u64(d.as_secs().min(MAX))
+    }
+    pub fn deserialize<'de, D>(d: D) -> Result<Duration, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let visitor = DurVisitor {
+            parse,
+            float_error: Some("expected delta-seconds (a whole number of seconds), got a float"),
+            overflow: OverflowPolicy::Saturate,
+            ..DurVisitor::default()
+        };
+        let v = d.deserialize_any(visitor)?;
+        Ok(v.min(Duration::from_secs(MAX)))
+    }
+}
+
+/// Single-key maps naming the unit: `{"h": 1}`, `{"ms": 250}`, `{"min": 5}`, `{"hours": 1.5}`.
+///
+/// Keys are the [`UNITS`] abbreviations, the English unit words, or `min`/`mins`, `sec`/`secs`,
+/// `hr`/`hrs` (any case). The value is a non-negative number; fractions round to the nearest
+/// nanosecond. Maps with zero or several keys are rejected as ambiguous. Serializes as
+/// `{unit: n}` in the coarsest exact unit (see [`auto_unit`](fn@crate::auto_unit)).
+pub mod unit_keyed {
+    use serde::ser::SerializeMap;
+
+    use super::*;
+
+    const ALIASES: &[(&str, u128)] = &[
+        ("min", Unit::Minutes.nanos()),
+        ("mins", Unit::Minutes.nanos()),
+        ("sec", Unit::Seconds.nanos()),
+        ("secs", Unit::Seconds.nanos()),
+        ("hr", Unit::Hours.nanos()),
+        ("hrs", Unit::Hours.nanos()),
+    ];
+
+    pub fn serialize<S>(d: &Duration, s: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let (n, unit) = super::auto_unit(d);
+        let mut map = s.serialize_map(Some(1))?;
+        map.serialize_entry(unit.suffix(), &n)?;
+        map.end()
+    }
+
+    pub fn deserialize<'de, D>(d: D) -> Result<Duration, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct KeyedVisitor;
+        impl<'de> Visitor<'de> for KeyedVisitor {
+            type Value = Duration;
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("a single-key map like {\"min\": 5}")
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Duration, A::Error>
+            where
+                A: de::MapAccess<'de>,
+            {
+                let key: String = map.next_key()?.ok_or_else(|| de::Error::invalid_length(0, &self))?;
+                let nanos = unit_nanos(&key).map_err(de::Error::custom)?;
+                let v = map.next_value_seed(Quantity(nanos))?;
+                if map.next_key::<de::IgnoredAny>()?.is_some() {
+                    return Err(de::Error::custom("expected a single unit key, found several"));
+                }
+                Ok(v)
+            }
+        }
+        d.deserialize_map(KeyedVisitor)
+    }
+
+    /// Nanoseconds per `name`, matched case-insensitively against the accepted keys.
+    pub(super) fn unit_nanos(name: &str) -> Result<u128, String> {
+        let lower = name.to_lowercase();
+        let words = Locale::English.words();
+        match UNITS.iter().chain(words).chain(ALIASES).find(|(n, _)| *n == lower) {
+            Some(&(_, nanos)) => Ok(nanos),
+            None => Err(format!("unknown unit '{name}' (e.g. d, h, m, s, ms, hours, secs)")),
+        }
+    }
+
+    /// Reads the numeric value once the key has fixed the unit length (in nanoseconds).
+    pub(super) struct Quantity(pub(super) u128);
+
+    impl<'de> de::DeserializeSeed<'de> for Quantity {
+        type Value = Duration;
+        fn deserialize<D>(self, d: D) -> Result<Duration, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            d.deserialize_any(self)
+        }
+    }
+
+    impl Visitor<'_> for Quantity {
+        type Value = Duration;
+        fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            f.write_str("a non-negative number")
+        }
+        fn visit_u64<E: de::Error>(self, v: u64) -> Result<Duration, E> {
+            DurVisitor { int_nanos: self.0, ..DurVisitor::default() }.visit_u64(v)
+        }
+        fn visit_i64<E: de::Error>(self, v: i64) -> Result<Duration, E> {
+            DurVisitor { int_nanos: self.0, ..DurVisitor::default() }.visit_i64(v)
+        }
+        fn visit_f64<E: de::Error>(self, v: f64) -> Result<Duration, E> {
+            if !v.is_finite() || v < 0.0 {
+                return Err(E::custom("expected a finite, non-negative number"));
+            }
+            let nanos = (v * self.0 as f64).round();
+            if nanos > MAX_NANOS as f64 {
+                return Err(E::custom("duration overflow"));
+            }
+            duration_from_nanos(nanos as u128).ok_or_else(|| E::custom("duration overflow"))
+        }
+    }
+}
+
+/// Unit-first pairs: `["h", 1]`, `["ms", 250]`, `["min", 1.5]`.
+///
+/// The unit is any [`unit_keyed`] key and the value a non-negative number, multiplied out to the
+/// nearest nanosecond. Exactly two elements. Serializes as `[unit, n]` in the coarsest exact unit,
+/// so output reads back unchanged.
+pub mod seq_unit_first {
+    use serde::ser::SerializeTuple;
+
+    use super::*;
+
+    pub fn serialize<S>(d: &Duration, s: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let (n, unit) = super::auto_unit(d);
+        let mut t = s.serialize_tuple(2)?;
+        t.serialize_element(unit.suffix())?;
+        t.serialize_element(&n)?;
+        t.end()
+    }
+
+    pub fn deserialize<'de, D>(d: D) -> Result<Duration, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct UnitFirstVisitor;
+        impl<'de> Visitor<'de> for UnitFirstVisitor {
+            type Value = Duration;
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("a [unit, number] pair like [\"m\", 5]")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Duration, A::Error>
+            where
+                A: de::SeqAccess<'de>,
+            {
+                let unit: String = seq.next_element()?.ok_or_else(|| de::Error::invalid_length(0, &self))?;
+                let nanos = unit_keyed::unit_nanos(&unit).map_err(de::Error::custom)?;
+                let v = seq
+                    .next_element_seed(unit_keyed::Quantity(nanos))?
+                    .ok_or_else(|| de::Error::invalid_length(1, &self))?;
+                if seq.next_element::<de::IgnoredAny>()?.is_some() {
+                    return Err(de::Error::invalid_length(3, &self));
+                }
+                Ok(v)
+            }
+        }
+        d.deserialize_tuple(2, UnitFirstVisitor)
+    }
+}
+
+/// Value-and-unit objects for charts: `{"value": 1.5, "unit": "hours"}`.
+///
+/// The unit is the largest of days to milliseconds that keeps `value` at 1 or more (zero is
+/// `{"value": 0, "unit": "seconds"}`), written with its [`long_name`](Unit::long_name); `value` is
+/// the exact `f64` ratio, so 90 minutes is `1.5` hours. On input the unit is any [`unit_keyed`]
+/// key and the product rounds to the nearest nanosecond. Both fields are required and unknown
+/// fields are rejected.
+pub mod auto_scaled {
+    use serde::ser::SerializeStruct;
+
+    use super::*;
+
+    const FIELDS: &[&str] = &["value", "unit"];
+
+    /// The unit and value [`serialize`] writes for `d`.
+    pub fn scale_of(d: &Duration) -> (f64, Unit) {
+        let nanos = d.as_nanos();
+        let unit = HUMAN_UNITS.into_iter().find(|u| nanos >= u.nanos());
+        let unit = unit.unwrap_or(if nanos == 0 { Unit::Seconds } else { Unit::Millis });
+        (nanos as f64 / unit.nanos() as f64, unit)
+    }
+
+    pub fn serialize<S>(d: &Duration, s: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let (value, unit) = scale_of(d);
+        let mut st = s.serialize_struct("Scaled", 2)?;
+        st.serialize_field("value", &value)?;
+        st.serialize_field("unit", unit.long_name())?;
+        st.end()
+    }
+
+    pub fn deserialize<'de, D>(d: D) -> Result<Duration, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct ScaledVisitor;
+        impl<'de> Visitor<'de> for ScaledVisitor {
+            type Value = Duration;
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("a {\"value\", \"unit\"} object")
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Duration, A::Error>
+            where
+                A: de::MapAccess<'de>,
+            {
+                let (mut value, mut unit) = (None::<f64>, None::<String>);
+                while let Some(key) = map.next_key::<String>()? {
+                    let duplicate = match key.as_str() {
+                        "value" => value.replace(map.next_value()?).is_some(),
+                        "unit" => unit.replace(map.next_value()?).is_some(),
+                        _ => return Err(de::Error::unknown_field(&key, FIELDS)),
+                    };
+                    if duplicate {
+                        return Err(de::Error::custom(format!("duplicate field `{key}`")));
+                    }
+                }
+                let value = value.ok_or_else(|| de::Error::missing_field("value"))?;
+                let unit = unit.ok_or_else(|| de::Error::missing_field("unit"))?;
+                let nanos = unit_keyed::unit_nanos(&unit).map_err(de::Error::custom)?;
+                scale(Duration::from_nanos(nanos as u64), value).map_err(|e| match e {
+                    ScaleError::InvalidFactor => de::Error::custom("value must be finite and non-negative"),
+                    ScaleError::Overflow => de::Error::custom(e),
+                })
+            }
+        }
+        d.deserialize_struct("Scaled", FIELDS, ScaledVisitor)
+    }
+}
+
+/// `Range<Duration>` windows as `{"start": "1h", "end": "2h"}`.
+///
+/// Both bounds are human on output and flexible on input; both fields are required and unknown
+/// fields are rejected. An inverted range (`start > end`) is an error; an empty one (`start == end`)
+/// is allowed.
+pub mod range {
+    use serde::ser::SerializeStruct;
+    use std::ops::Range;
+
+    use super::*;
+
+    const FIELDS: &[&str] = &["start", "end"];
+
+    pub fn serialize<S>(r: &Range<Duration>, s: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut st = s.serialize_struct("Range", 2)?;
+        st.serialize_field("start", &ExtDuration(r.start))?;
+        st.serialize_field("end", &ExtDuration(r.end))?;
+        st.end()
+    }
+
+    pub fn deserialize<'de, D>(d: D) -> Result<Range<Duration>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct RangeVisitor;
+        impl<'de> Visitor<'de> for RangeVisitor {
+            type Value = Range<Duration>;
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("a {\"start\", \"end\"} object of durations")
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Range<Duration>, A::Error>
+            where
+                A: de::MapAccess<'de>,
+            {
+                let (mut start, mut end) = (None, None);
+                while let Some(key) = map.next_key::<String>()? {
+                    let slot = match key.as_str() {
+                        "start" => &mut start,
+                        "end" => &mut end,
+                        _ => return Err(de::Error::unknown_field(&key, FIELDS)),
+                    };
+                    if slot.is_some() {
+                        return Err(de::Error::custom(format!("duplicate field `{key}`")));
+                    }
+                    *slot = Some(map.next_value::<ExtDuration>()?.0);
+                }
+                let start = start.ok_or_else(|| de::Error::missing_field("start"))?;
+                let end = end.ok_or_else(|| de::Error::missing_field("end"))?;
+                if start > end {
+                    let (start, end) = (to_human_string(&start), to_human_string(&end));
+                    return Err(de::Error::custom(format!("inverted range: start {start} is after end {end}")));
+                }
+                Ok(start..end)
+            }
+        }
+        d.deserialize_struct("Range", FIELDS, RangeVisitor)
+    }
+}
+
+/// Percentages of a known duration: `"50%"` of a 2s period is 1s.
+///
+/// `percent_of` scales any base (`"150%"` is allowed); `percent_of_period` is the duty-cycle form
+/// and rejects values above 100%. Up to 9 fraction digits are honored (`"12.5%"`), results round to
+/// the nearest nanosecond, and whitespace around the number and before `%` is ignored.
+pub mod percent {
+    use super::*;
+
+    pub fn percent_of(s: &str, base: Duration) -> Result<Duration, String> {
+        let (num, den) = parse_percent(s)?;
+        let base = base.as_nanos();
+        // base * num / den without the intermediate product: den is at most 100 * 10^9
+        let (q, r) = (base / den, base % den);
+        let nanos = q
+            .checked_mul(num)
+            .zip(r.checked_mul(num))
+            .and_then(|(whole, part)| whole.checked_add(part / den + (part % den * 2 >= den) as u128))
+            .ok_or_else(|| "duration overflow".to_string())?;
+        duration_from_nanos(nanos).ok_or_else(|| "duration too large".into())
+    }
+
+    pub fn percent_of_period(s: &str, period: Duration) -> Result<Duration, String> {
+        let (num, den) = parse_percent(s)?;
+        if num > den {
+            return Err(format!("duty cycle '{}' exceeds 100%", s.trim()));
+        }
+        percent_of(s, period)
+    }
+
+    /// `"12.5%"` → `(125, 1000)`, the fraction of the base as numerator / denominator.
+    fn parse_percent(s: &str) -> Result<(u128, u128), String> {
+        let body = s.trim().strip_suffix('%').ok_or_else(|| format!("expected a percentage like '50%', got '{s}'"))?;
+        let body = body.trim_end();
+        let (int, frac) = body.split_once('.').unwrap_or((body, ""));
+        let digits = |t: &str| t.bytes().all(|b| b.is_ascii_digit());
+        if int.is_empty() || !digits(int) || !digits(frac) || (body.contains('.') && frac.is_empty()) {
+            return Err(format!("invalid percentage '{}'", s.trim()));
+        }
+        let frac = &frac[..frac.len().min(9)];
+        let num: u128 = format!("{int}{frac}").parse().map_err(|_| "duration overflow".to_string())?;
+        Ok((num, 100 * 10u128.pow(frac.len() as u32)))
+    }
+}
+
+/// Whole seconds in base 36 (`"2s"` is 100 seconds, `"2s0"` one hour), for short URL parameters.
+///
+/// Not human-readable: `"1h"` here means 53 seconds, so keep this to machine-made values.
+/// Sub-second parts are truncated like [`secs`]; digits are read case-insensitively.
+pub mod compact_secs {
+    use super::*;
+
+    pub fn to_base36(d: &Duration) -> String {
+        let mut n = d.as_secs();
+        let mut digits = Vec::new();
+        loop {
+            digits.push(char::from_digit((n % 36) as u32, 36).unwrap_or('0'));
+            n /= 36;
+            if n == 0 {
+                break;
+            }
+        }
+        digits.iter().rev().collect()
+    }
+
+    pub fn parse(s: &str) -> Result<Duration, String> {
+        if s.is_empty() || !s.bytes().all(|b| b.is_ascii_alphanumeric()) {
+            return Err(format!("expected base-36 seconds, got '{s}'"));
+        }
+        u64::from_str_radix(s, 36).map(Duration::from_secs).map_err(|_| "duration overflow".into())
+    }
+
+    pub fn serialize<S>(d: &Duration, s: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        s.serialize_str(&to_base36(d))
+    }
+    pub fn deserialize<'de, D>(d: D) -> Result<Duration, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = <std::borrow::Cow<str>>::deserialize(d)?;
+        parse(&s).map_err(de::Error::custom)
+    }
+}
+
+/// One unit sized for latencies: `"850ns"`, `"1.5µs"`, `"250µs"`, `"12.3ms"`, `"2.05s"`.
+///
+/// Picks the largest of ns/µs/ms/s that keeps the value at 1 or more and shows up to 3
+/// significant digits, rounded half-up (seconds are never promoted further: `"5400s"`). Zero is
+/// `"0s"`. Input is the regular flexible grammar, which reads these strings back.
+pub mod latency {
+    use super::*;
+
+    const UNITS: [(u128, &str); 4] = [(1, "ns"), (1_000, "µs"), (1_000_000, "ms"), (1_000_000_000, "s")];
+
+    pub fn to_latency_string(d: &Duration) -> String {
+        let n = d.as_nanos();
+        if n == 0 {
+            return "0s".into();
+        }
+        let mut idx = UNITS.iter().rposition(|&(unit, _)| n >= unit).unwrap_or(0);
+        loop {
+            let (unit, name) = UNITS[idx];
+            let decimals = match n / unit {
+                _ if unit == 1 => 0,
+                0..=9 => 2,
+                10..=99 => 1,
+                _ => 0,
+            };
+            let scale = 10u128.pow(decimals);
+            let scaled = (n * scale + unit / 2) / unit;
+            // 999.7µs rounds to 1000µs: say 1ms instead
+            if scaled >= 1000 * scale && idx + 1 < UNITS.len() {
+                idx += 1;
+                continue;
+            }
+            let (int, frac) = (scaled / scale, scaled % scale);
+            let mut out = int.to_string();
+            if frac > 0 {
+                let digits = format!("{frac:0width$}", width = decimals as usize);
+                let _ = write!(out, ".{}", digits.trim_end_matches('0'));
+            }
+            out.push_str(name);
+            return out;
+        }
+    }
+
+    pub fn serialize<S>(d: &Duration, s: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        s.serialize_str(&to_latency_string(d))
+    }
+    pub fn deserialize<'de, D>(d: D) -> Result<Duration, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        super::deserialize(d)
+    }
+}
+
+/// Firestore / JavaScript timestamp objects: `{"_seconds": 1700000000, "_nanoseconds": 500}`.
+///
+/// The pair is read as a plain `Duration`: for timestamps that is the offset since the UNIX epoch,
+/// so add it to `UNIX_EPOCH` to get a `SystemTime`. Both keys are required, `_nanoseconds` must be
+/// below one second, and negative values (pre-1970 timestamps) are rejected.
+pub mod firebase {
+    use serde::ser::SerializeStruct;
+
+    use super::*;
+
+    const FIELDS: &[&str] = &["_seconds", "_nanoseconds"];
+
+    pub fn serialize<S>(d: &Duration, s: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut st = s.serialize_struct("Timestamp", 2)?;
+        st.serialize_field("_seconds", &d.as_secs())?;
+        st.serialize_field("_nanoseconds", &d.subsec_nanos())?;
+        st.end()
+    }
+
+    pub fn deserialize<'de, D>(d: D) -> Result<Duration, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct TimestampVisitor;
+        impl<'de> Visitor<'de> for TimestampVisitor {
+            type Value = Duration;
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("a {\"_seconds\", \"_nanoseconds\"} object")
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Duration, A::Error>
+            where
+                A: de::MapAccess<'de>,
+            {
+                let (mut secs, mut nanos) = (None, None);
+                while let Some(key) = map.next_key::<String>()? {
+                    let slot = match key.as_str() {
+                        "_seconds" => &mut secs,
+                        "_nanoseconds" => &mut nanos,
+                        _ => return Err(de::Error::unknown_field(&key, FIELDS)),
+                    };
+                    if slot.is_some() {
+                        return Err(de::Error::custom(format!("duplicate field `{key}`")));
+                    }
+                    *slot = Some(map.next_value::<u64>()?);
+                }
+                let secs = secs.ok_or_else(|| de::Error::missing_field("_seconds"))?;
+                let nanos = nanos.ok_or_else(|| de::Error::missing_field("_nanoseconds"))?;
+                if nanos >= 1_000_000_000 {
+                    return Err(de::Error::custom("`_nanoseconds` must be below 1000000000"));
+                }
+                Ok(Duration::new(secs, nanos as u32))
+            }
+        }
+        d.deserialize_struct("Timestamp", FIELDS, TimestampVisitor)
+    }
+}
+
+/// ISO 8601 durations: `"PT1H30M"`, `"P2DT3H"`, `"PT0.25S"`, `"P1W"`. No extra dependencies.
+///
+/// Output uses `D`, `H`, `M` and `S` (days are 24 hours), largest first, with a trimmed fraction
+/// on the seconds; zero is `"PT0S"`. Input also takes weeks, lowercase letters and a `,` decimal
+/// mark, and allows a fraction on the last component only. Years and months are rejected because
+/// they have no fixed length; plain numbers are read as seconds, like [`deserialize`].
+///
+/// Designators must follow the standard order, each at most once, so `"PT30M1H"` and `"PT1H1H"`
+/// are errors; [`iso8601::lenient`] accepts any order.
+pub mod iso8601 {
+    use super::*;
+
+    pub fn to_iso8601_string(d: &Duration) -> String {
+        format_iso(d, true, None)
+    }
+
+    /// Like [`to_iso8601_string`], with exactly `digits` fractional-second digits: `3` writes
+    /// 250ms as `"PT0.250S"`, `6` as `"PT0.250000S"`. Seconds round half-up to that precision
+    /// and, for `digits > 0`, are always written (`"PT1H0.000S"`); `0` drops the fraction.
+    ///
+    /// # Panics
+    /// If `digits` is above 9.
+    pub fn to_iso8601_string_with_precision(d: &Duration, digits: usize) -> String {
+        assert!(digits <= 9, "at most 9 fractional digits, got {digits}");
+        format_iso(d, true, Some(digits))
+    }
+
+    /// Strict: designators must come in the standard order (`W D T H M S`), each at most once.
+    pub fn parse(s: &str) -> Result<Duration, String> {
+        parse_designators(s, true)
+    }
+
+    /// `ordered` enforces the standard designator order; either way each appears at most once.
+    fn parse_designators(s: &str, ordered: bool) -> Result<Duration, String> {
+        let t = s.trim();
+        let body = t
+            .strip_prefix(&['P', 'p'][..])
+            .ok_or_else(|| format!("expected an ISO 8601 duration like 'PT1H30M', got '{t}'"))?;
+        let (date, time) = match body.find(&['T', 't'][..]) {
+            Some(i) if i + 1 == body.len() => return Err("expected time components after 'T'".into()),
+            Some(i) => (&body[..i], Some(&body[i + 1..])),
+            None => (body, None),
+        };
+        if date.is_empty() && time.is_none() {
+            return Err("empty ISO 8601 duration".into());
+        }
+        const DATE: &[(char, u128)] = &[('W', 7 * Unit::Days.nanos()), ('D', Unit::Days.nanos())];
+        const TIME: &[(char, u128)] =
+            &[('H', Unit::Hours.nanos()), ('M', Unit::Minutes.nanos()), ('S', Unit::Seconds.nanos())];
+        let mut total = 0u128;
+        let mut fraction_seen = false;
+        for (part, designators, in_date) in [(date, DATE, true), (time.unwrap_or(""), TIME, false)] {
+            let mut rest = part;
+            let (mut next, mut used) = (0, 0u8);
+            while !rest.is_empty() {
+                if fraction_seen {
+                    return Err("a fraction is only allowed on the last component".into());
+                }
+                let int_len = rest.bytes().take_while(u8::is_ascii_digit).count();
+                if int_len == 0 {
+                    return Err(format!("expected number in '{t}'"));
+                }
+                let n: u128 = rest[..int_len].parse().map_err(|_| "duration overflow".to_string())?;
+                rest = &rest[int_len..];
+                let (mut frac, mut scale) = (0u128, 1u128);
+                if let Some(digits) = rest.strip_prefix(&['.', ','][..]) {
+                    let frac_len = digits.bytes().take_while(u8::is_ascii_digit).count();
+                    if frac_len == 0 {
+                        return Err(format!("expected digits after the decimal mark in '{t}'"));
+                    }
+                    for b in digits.bytes().take(frac_len.min(MAX_FRACTION_DIGITS)) {
+                        frac = frac * 10 + (b - b'0') as u128;
+                        scale *= 10;
+                    }
+                    rest = &digits[frac_len..];
+                    fraction_seen = true;
+                }
+                let designator = rest.chars().next().map(|c| c.to_ascii_uppercase());
+                let Some(c) = designator else {
+                    return Err(format!("expected a designator after the number in '{t}'"));
+                };
+                let from = if ordered { next } else { 0 };
+                let Some(pos) = designators[from..].iter().position(|&(d, _)| d == c).map(|p| from + p) else {
+                    return Err(match c {
+                        'Y' | 'M' if in_date => "years and months have no fixed length; use weeks or days".to_string(),
+                        _ => format!("unexpected or out-of-order designator '{c}' in '{t}'"),
+                    });
+                };
+                if used & 1 << pos != 0 {
+                    return Err(format!("duplicate designator '{c}' in '{t}'"));
+                }
+                used |= 1 << pos;
+                let nanos_per_unit = designators[pos].1;
+                next = pos + 1;
+                total = n
+                    .checked_mul(nanos_per_unit)
+                    .and_then(|whole| whole.checked_add((frac * nanos_per_unit + scale / 2) / scale))
+                    .and_then(|inc| total.checked_add(inc))
+                    .ok_or_else(|| "duration overflow".to_string())?;
+                rest = &rest[1..];
+            }
+        }
+        duration_from_nanos(total).ok_or_else(|| "duration too large".into())
+    }
+
+    /// `days` decides whether whole days get their own `D` component or stay in the hours;
+    /// `digits` fixes the fractional-second digits, `None` trims them to the minimum.
+    fn format_iso(d: &Duration, days: bool, digits: Option<usize>) -> String {
+        let d = match digits {
+            Some(n) => {
+                let (total, step) = (d.as_nanos(), 10u128.pow(9 - n as u32));
+                let down = total - total % step;
+                let up = if total % step * 2 >= step { duration_from_nanos(down + step) } else { None };
+                up.or_else(|| duration_from_nanos(down)).unwrap_or(*d)
+            }
+            None => *d,
+        };
+        let (secs, nanos) = (d.as_secs(), d.subsec_nanos());
+        // A fixed fraction is written even when the seconds are zero.
+        let fixed = matches!(digits, Some(1..));
+        if secs == 0 && nanos == 0 && !fixed {
+            return "PT0S".into();
+        }
+        let mut out = String::from("P");
+        let (day_count, secs) = if days { (secs / 86_400, secs % 86_400) } else { (0, secs) };
+        if day_count > 0 {
+            let _ = write!(out, "{day_count}D");
+        }
+        if secs > 0 || nanos > 0 || fixed {
+            out.push('T');
+            let (h, m, s) = (secs / 3600, secs / 60 % 60, secs % 60);
+            if h > 0 {
+                let _ = write!(out, "{h}H");
+            }
+            if m > 0 {
+                let _ = write!(out, "{m}M");
+            }
+            let frac = format!("{nanos:09}");
+            match digits {
+                None if nanos > 0 => {
+                    let _ = write!(out, "{s}.{}S", frac.trim_end_matches('0'));
+                }
+                Some(n @ 1..) => {
+                    let _ = write!(out, "{s}.{}S", &frac[..n]);
+                }
+                _ if s > 0 => {
+                    let _ = write!(out, "{s}S");
+                }
+                _ => {}
+            }
+        }
+        out
+    }
+
+    pub fn serialize<S>(d: &Duration, s: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        s.serialize_str(&to_iso8601_string(d))
+    }
+
+    /// [`serialize`] with a fixed number of fractional-second digits, see
+    /// [`to_iso8601_string_with_precision`]. Wrap it for `serialize_with`:
+    ///
+    /// ```
+    /// # use std::time::Duration;
+    /// mod iso_millis {
+    ///     pub fn serialize<S: serde::Serializer>(d: &std::time::Duration, s: S) -> Result<S::Ok, S::Error> {
+    ///         serde_ext_duration::iso8601::serialize_with_precision(d, 3, s)
+    ///     }
+    ///     pub use serde_ext_duration::iso8601::deserialize;
+    /// }
+    ///
+    /// #[derive(serde::Serialize, serde::Deserialize)]
+    /// struct Cfg {
+    ///     #[serde(with = "iso_millis")]
+    ///     t: Duration,
+    /// }
+    /// # let json = serde_json::to_string(&Cfg { t: Duration::from_millis(250) }).unwrap();
+    /// # assert_eq!(json, r#"{"t":"PT0.250S"}"#);
+    /// ```
+    ///
+    /// # Panics
+    /// If `digits` is above 9.
+    pub fn serialize_with_precision<S>(d: &Duration, digits: usize, s: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        s.serialize_str(&to_iso8601_string_with_precision(d, digits))
+    }
+
+    pub fn deserialize<'de, D>(d: D) -> Result<Duration, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        d.deserialize_any(DurVisitor { parse, ..DurVisitor::default() })
+    }
+
+    /// [`iso8601`](super) output without a date part: days stay in the hours, so two days is
+    /// `"PT48H"`, for consumers that reject `D`. Input is the same as [`iso8601`](super).
+    pub mod time {
+        use super::*;
+
+        pub fn to_iso8601_string(d: &Duration) -> String {
+            format_iso(d, false, None)
+        }
+
+        pub fn serialize<S>(d: &Duration, s: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            s.serialize_str(&to_iso8601_string(d))
+        }
+        pub fn deserialize<'de, D>(d: D) -> Result<Duration, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            super::deserialize(d)
+        }
+    }
+
+    /// [`iso8601`](super) with the designators in any order: `"PT30M1H"` is 1h 30m, like the
+    /// order-free native format. Weeks and days still come before `T`, each designator may appear
+    /// once and only the last component written may carry a fraction. Output is the same as
+    /// [`iso8601`](super).
+    pub mod lenient {
+        use super::*;
+
+        pub fn parse(s: &str) -> Result<Duration, String> {
+            parse_designators(s, false)
+        }
+
+        pub fn serialize<S>(d: &Duration, s: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            super::serialize(d, s)
+        }
+        pub fn deserialize<'de, D>(d: D) -> Result<Duration, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            d.deserialize_any(DurVisitor { parse, ..DurVisitor::default() })
+        }
+    }
+}
+
+/// Terse `"1h30"`-style strings: a trailing number without a unit takes the unit one step below
+/// the token before it. Opt-in, because `"1h30"` is an error everywhere else.
+///
+/// The steps are `w` → `d` → `h` → `m` → `s` → `ms`, so `"1h30"` is 1h30m, `"1m30"` is 1m30s and
+/// `"2d 12"` is 2d12h; any spelling [`parse_str`] knows works for the preceding unit. Only the last
+/// number may drop its unit. A bare `"30"` is an error (there is no preceding unit to step down
+/// from), as is a trailing number after `ms` or smaller. Strings that end in a unit are read like
+/// [`parse_str`]. On the serde side, numbers and digit-only strings are still whole seconds, like
+/// [`deserialize`]; serializes as plain human output.
+pub mod trailing_unit {
+    use super::*;
+
+    pub fn parse(s: &str) -> Result<Duration, String> {
+        let t = s.trim();
+        let num_start = t.trim_end_matches(|c: char| c.is_ascii_digit() || c == '.').len();
+        if num_start == t.len() {
+            return parse_str(t);
+        }
+        let number = &t[num_start..];
+        let head = t[..num_start].trim_end();
+        let unit = &head[head.trim_end_matches(char::is_alphabetic).len()..];
+        if unit.is_empty() {
+            return Err(format!("trailing number '{number}' needs a preceding unit, e.g. '1h{number}'"));
+        }
+        let prev = parse_str(&format!("1{unit}")).map_err(|e| format!("in unit '{unit}': {e}"))?;
+        let next = match prev.as_nanos() {
+            n if n == 7 * Unit::Days.nanos() => Unit::Days,
+            n => match HUMAN_UNITS.iter().position(|u| u.nanos() == n) {
+                Some(i) if i + 1 < HUMAN_UNITS.len() => HUMAN_UNITS[i + 1],
+                _ => return Err(format!("no smaller unit for the trailing number '{number}' after '{unit}'")),
+            },
+        };
+        parse_str(&format!("{t}{}", next.suffix()))
+    }
+
+    pub fn serialize<S>(d: &Duration, s: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        super::serialize_human(d, s)
+    }
+    pub fn deserialize<'de, D>(d: D) -> Result<Duration, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        d.deserialize_any(DurVisitor { parse, ..DurVisitor::default() })
+    }
+}
+
+/// Seconds as an exact [`rust_decimal::Decimal`] (`rust_decimal` feature), e.g. for billing.
+///
+/// Every `Duration` fits, so `1.000000001` seconds survives the round trip that `secs_f64_ms`
+/// would round to milliseconds. The value is written the way `rust_decimal` serializes a
+/// `Decimal`: a string such as `"1.5"` by default, keeping it exact in JSON. On input, digits
+/// beyond nanoseconds round to the nearest nanosecond; negative values are rejected.
+#[cfg(feature = "rust_decimal")]
+pub mod decimal_secs {
+    use super::*;
+    use rust_decimal::prelude::ToPrimitive;
+    use rust_decimal::Decimal;
+
+    const NANOS_PER_SEC: Decimal = Decimal::from_parts(1_000_000_000, 0, 0, false, 0);
+
+    /// `d` in seconds with trailing zeros trimmed (`Duration::from_millis(1500)` is `1.5`).
+    pub fn to_decimal(d: &Duration) -> Decimal {
+        // `Duration::MAX` is about 1.8e28 ns, well inside a `Decimal`'s 96-bit mantissa.
+        Decimal::from_i128_with_scale(d.as_nanos() as i128, 9).normalize()
+    }
+
+    pub fn from_decimal(secs: Decimal) -> Result<Duration, String> {
+        if secs.is_sign_negative() && !secs.is_zero() {
+            return Err("duration cannot be negative".into());
+        }
+        let nanos = secs.checked_mul(NANOS_PER_SEC).ok_or_else(|| "duration overflow".to_string())?;
+        nanos.round().to_u128().and_then(duration_from_nanos).ok_or_else(|| "duration too large".into())
+    }
+
+    pub fn serialize<S>(d: &Duration, s: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        Serialize::serialize(&to_decimal(d), s)
+    }
+    pub fn deserialize<'de, D>(d: D) -> Result<Duration, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        from_decimal(<Decimal as Deserialize>::deserialize(d)?).map_err(de::Error::custom)
+    }
+}
+
+/// **A guess**: integers at or above a threshold are read as milliseconds, smaller ones as seconds.
+///
+/// This is for ingesting integer data that mixes the two units and cannot be fixed upstream. It
+/// is wrong whenever a real value lands on the other side of the line: with the default
+/// [`DEFAULT_THRESHOLD`](heuristic::DEFAULT_THRESHOLD) of 10^11, 10^11 seconds (about 3170
+/// years) read as 3.2 years, and 99 999 999 999 ms read as seconds. Prefer an explicit unit
+/// whenever you control the data. Digit strings follow the same rule; floats and unit strings are
+/// read like [`deserialize`]. Output is human, which never needs the guess to read back. Pick
+/// another line with [`deserialize_with_threshold`](heuristic::deserialize_with_threshold); see
+/// [modules that take arguments](crate#modules-that-take-arguments).
+pub mod heuristic {
+    use super::*;
+
+    /// 10^11: as seconds that would be over 3000 years, as milliseconds about 3.2 years.
+    pub const DEFAULT_THRESHOLD: u64 = 100_000_000_000;
+
+    pub fn serialize<S>(d: &Duration, s: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        super::serialize_human(d, s)
+    }
+
+    pub fn deserialize<'de, D>(d: D) -> Result<Duration, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserialize_with_threshold(d, DEFAULT_THRESHOLD)
+    }
+
+    pub fn deserialize_with_threshold<'de, D>(d: D, threshold: u64) -> Result<Duration, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct GuessVisitor(u64);
+        impl Visitor<'_> for GuessVisitor {
+            type Value = Duration;
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                DurVisitor::default().expecting(f)?;
+                write!(f, " (integers from {} on are milliseconds)", self.0)
+            }
+            fn visit_u64<E: de::Error>(self, v: u64) -> Result<Duration, E> {
+                if v >= self.0 {
+                    Ok(Duration::from_millis(v))
+                } else {
+                    DurVisitor::default().visit_u64(v)
+                }
+            }
+            fn visit_i64<E: de::Error>(self, v: i64) -> Result<Duration, E> {
+                match u64::try_from(v) {
+                    Ok(v) => self.visit_u64(v),
+                    Err(_) => DurVisitor::default().visit_i64(v),
+                }
+            }
+            fn visit_f64<E: de::Error>(self, v: f64) -> Result<Duration, E> {
+                DurVisitor::default().visit_f64(v)
+            }
+            fn visit_str<E: de::Error>(self, v: &str) -> Result<Duration, E> {
+                let t = v.trim();
+                match t.parse::<u64>() {
+                    Ok(n) if t.bytes().all(|b| b.is_ascii_digit()) => self.visit_u64(n),
+                    _ => DurVisitor::default().visit_str(v),
+                }
+            }
+        }
+        d.deserialize_any(GuessVisitor(threshold))
+    }
+}
+
+/// Milliseconds as a zero-padded string, `"00000065000"`, so keys sort lexicographically in time
+/// order (e.g. in a KV store).
+///
+/// [`serialize`](millis_padded::serialize) pads to [`DEFAULT_WIDTH`](millis_padded::DEFAULT_WIDTH)
+/// digits, enough for about 3.2 years; a value that needs more digits than the width is an error
+/// rather than a key that sorts wrong. Milliseconds round to nearest, as in [`millis`]. On input,
+/// leading zeros are dropped and the digits read as milliseconds, whatever the width (an unquoted
+/// integer works too). For another width, call
+/// [`serialize_with_width`](millis_padded::serialize_with_width) from a `serialize_with` function.
+pub mod millis_padded {
+    use super::*;
+
+    /// 11 digits: up to 99 999 999 999 ms.
+    pub const DEFAULT_WIDTH: usize = 11;
+
+    /// `d` in milliseconds, zero-padded to `width` digits.
+    pub fn to_padded_string(d: &Duration, width: usize) -> Result<String, SerializeDurationError> {
+        let ms = rounded_millis(d);
+        let s = format!("{ms:0width$}");
+        if s.len() > width {
+            return Err(SerializeDurationError::TooWide { digits: s.len(), width });
+        }
+        Ok(s)
+    }
+
+    fn parse(s: &str) -> Result<Duration, String> {
+        Err(format!("expected a zero-padded millisecond count like '00000065000', got '{s}'"))
+    }
+
+    pub fn serialize<S>(d: &Duration, s: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serialize_with_width(d, DEFAULT_WIDTH, s)
+    }
+
+    pub fn serialize_with_width<S>(d: &Duration, width: usize, s: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serialize_text(s, to_padded_string(d, width))
+    }
+
+    pub fn deserialize<'de, D>(d: D) -> Result<Duration, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let visitor = DurVisitor {
+            parse,
+            int_nanos: Unit::Millis.nanos(),
+            float_error: Some(NO_FLOATS),
+            ..DurVisitor::default()
+        };
+        d.deserialize_any(visitor)
+    }
+}
+
+/// Forgiving input for free-text fields: stray punctuation pasted along with the value, as in
+/// `"1h;"` or `"30m."`, and an "about" tilde, as in `"~30m"`, are ignored.
+///
+/// Any run of `.`, `;` and `,` at the very end and a single `~` at the very start (surrounding
+/// whitespace included) are dropped before the rest goes through [`parse_str`]; the estimate is
+/// taken at face value, and punctuation anywhere else is still an error. Keep the
+/// strict modules for config files, where such a character more likely means a typo. Serializes as
+/// plain human output.
+pub mod lenient {
+    use super::*;
+
+    pub fn parse(s: &str) -> Result<Duration, String> {
+        let t = s.trim();
+        let t = t.strip_prefix('~').unwrap_or(t);
+        parse_str(t.trim_end_matches(&['.', ';', ','][..]))
+    }
+
+    pub fn serialize<S>(d: &Duration, s: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        super::serialize_human(d, s)
+    }
+    pub fn deserialize<'de, D>(d: D) -> Result<Duration, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        d.deserialize_any(DurVisitor { parse, ..DurVisitor::default() })
+    }
+}
+
+/// Both spellings at once for public APIs: `{"iso": "PT1H30M", "seconds": 5400}`.
+///
+/// `iso` is exact ([`iso8601`] output); `seconds` is whole seconds, truncated like [`secs`], for
+/// clients that would rather not parse ISO 8601. On input `iso` takes precedence and `seconds` is
+/// only used when `iso` is absent; `seconds` is then read flexibly (`5400`, `"90m"`). At least one
+/// field is required, each value must be valid even when unused, and unknown fields are rejected.
+pub mod interop {
+    use serde::ser::SerializeStruct;
+
+    use super::*;
+
+    const FIELDS: &[&str] = &["iso", "seconds"];
+
+    pub fn serialize<S>(d: &Duration, s: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut st = s.serialize_struct("Duration", 2)?;
+        st.serialize_field("iso", &super::iso8601::to_iso8601_string(d))?;
+        st.serialize_field("seconds", &d.as_secs())?;
+        st.end()
+    }
+
+    pub fn deserialize<'de, D>(d: D) -> Result<Duration, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct InteropVisitor;
+        impl<'de> Visitor<'de> for InteropVisitor {
+            type Value = Duration;
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("an {\"iso\", \"seconds\"} object")
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Duration, A::Error>
+            where
+                A: de::MapAccess<'de>,
+            {
+                let (mut iso, mut secs) = (None, None);
+                while let Some(key) = map.next_key::<String>()? {
+                    let (slot, value) = match key.as_str() {
+                        "iso" => {
+                            let text: String = map.next_value()?;
+                            (&mut iso, iso8601::parse(&text).map_err(de::Error::custom)?)
+                        }
+                        "seconds" => (&mut secs, map.next_value::<ExtDuration>()?.0),
+                        _ => return Err(de::Error::unknown_field(&key, FIELDS)),
+                    };
+                    if slot.is_some() {
+                        return Err(de::Error::custom(format!("duplicate field `{key}`")));
+                    }
+                    *slot = Some(value);
+                }
+                iso.or(secs).ok_or_else(|| de::Error::missing_field("iso"))
+            }
+        }
+        d.deserialize_struct("Duration", FIELDS, InteropVisitor)
+    }
+}
+
+/// `jiff`'s friendly duration format (`jiff` feature), e.g. `"1h 30m 250ms"` or `"72h 1s"`.
+///
+/// Output is what `format!("{:#}", jiff::SignedDuration)` prints: hours as the largest unit and
+/// full nanosecond precision, so `jiff` reads it back as a `SignedDuration` or a `Span`. Durations
+/// beyond `i64::MAX` seconds have no `SignedDuration` and fail to serialize. Strings are parsed by
+/// `jiff` (friendly or ISO 8601, days rejected as calendar units); negative values are rejected and
+/// plain numbers are seconds, like [`deserialize`](crate::deserialize).
+///
+/// The plain [`human`] output is a subset of the same grammar, except that its `d` unit only
+/// parses as a `jiff::Span`.
+#[cfg(feature = "jiff")]
+pub mod jiff_friendly {
+    use super::*;
+    use ::jiff::SignedDuration;
+
+    /// `Err(TooLarge)` past `jiff::SignedDuration::MAX` (`i64::MAX` seconds).
+    pub fn to_jiff_string(d: &Duration) -> Result<String, SerializeDurationError> {
+        let sd = SignedDuration::try_from(*d).map_err(|_| SerializeDurationError::TooLarge)?;
+        Ok(format!("{sd:#}"))
+    }
+
+    pub fn parse(s: &str) -> Result<Duration, String> {
+        let sd: SignedDuration = s.trim().parse().map_err(|e| format!("{e}"))?;
+        if sd.is_negative() {
+            return Err("negative duration not allowed".into());
+        }
+        Duration::try_from(sd).map_err(|e| format!("{e}"))
+    }
+
+    pub fn serialize<S>(d: &Duration, s: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serialize_text(s, to_jiff_string(d))
+    }
+    pub fn deserialize<'de, D>(d: D) -> Result<Duration, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        d.deserialize_any(DurVisitor { parse, ..DurVisitor::default() })
+    }
+}
+
+/// Fixed-width log timestamps: `"02:03:04.250"`, or `"1d 02:03:04.250"` from one day up.
+///
+/// Every field is zero-padded and the milliseconds are always written (`"00:00:05.000"`), so
+/// values line up in columns and grep alike. Input must follow the same template, hours below 24.
+pub mod log_format {
+    use super::*;
+
+    const TEMPLATE: &str = "expected [Dd ]HH:MM:SS.mmm";
+
+    /// `"1d 02:03:04.250"`; milliseconds are rounded half-up.
+    pub fn to_log_string(d: &Duration) -> String {
+        let ms = rounded_millis(d).min(MAX_MILLIS);
+        let (secs, sub_ms) = (ms / 1000, ms % 1000);
+        let (days, secs) = (secs / 86_400, secs % 86_400);
+        let clock = format!("{:02}:{:02}:{:02}.{sub_ms:03}", secs / 3600, secs / 60 % 60, secs % 60);
+        if days > 0 {
+            format!("{days}d {clock}")
+        } else {
+            clock
+        }
+    }
+
+    pub fn parse(s: &str) -> Result<Duration, String> {
+        let s = s.trim();
+        let (days, clock) = match s.split_once("d ") {
+            Some((days, clock)) if !days.is_empty() && days.bytes().all(|b| b.is_ascii_digit()) => {
+                (days.parse::<u64>().map_err(|_| "duration overflow".to_string())?, clock)
+            }
+            Some(_) => return Err(TEMPLATE.into()),
+            None => (0, s),
+        };
+        let b = clock.as_bytes();
+        let layout = b.len() == 12 && b[2] == b':' && b[5] == b':' && b[8] == b'.';
+        if !layout || !b.iter().enumerate().all(|(i, c)| matches!(i, 2 | 5 | 8) || c.is_ascii_digit()) {
+            return Err(TEMPLATE.into());
+        }
+        let field = |at: usize, width: usize| clock[at..at + width].parse::<u64>().unwrap_or(0);
+        let (h, m, sec, ms) = (field(0, 2), field(3, 2), field(6, 2), field(9, 3));
+        if h >= 24 || m >= 60 || sec >= 60 {
+            return Err(format!("clock field out of range in '{clock}'"));
+        }
+        days.checked_mul(86_400)
+            .and_then(|v| v.checked_add(h * 3600 + m * 60 + sec))
+            .map(|secs| Duration::new(secs, ms as u32 * 1_000_000))
+            .ok_or_else(|| "duration overflow".into())
+    }
+
+    pub fn serialize<S>(d: &Duration, s: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        s.serialize_str(&to_log_string(d))
+    }
+    pub fn deserialize<'de, D>(d: D) -> Result<Duration, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        d.deserialize_any(DurVisitor { parse, ..DurVisitor::default() })
+    }
+}
+
+/// Strictly positive durations: flexible input, but `0`, `"0s"` and the like are rejected.
+///
+/// A zero timeout or interval is almost always a configuration mistake; this catches it at load
+/// time. Human output on serialize. For optional fields use [`opt::positive`].
+pub mod positive {
+    use super::*;
+
+    pub fn serialize<S>(d: &Duration, s: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        super::serialize_human(d, s)
+    }
+    pub fn deserialize<'de, D>(d: D) -> Result<Duration, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        match super::deserialize(d)? {
+            Duration::ZERO => Err(de::Error::custom("duration must be greater than zero")),
+            dur => Ok(dur),
+        }
+    }
+}
+
+/// Relative offsets with keywords: `"now"` and `"immediately"` both mean [`Duration::ZERO`].
+///
+/// Keywords match whole strings, ignoring ASCII case and surrounding whitespace; anything else
+/// goes to the flexible parser. Serializes as human, so zero is `"0s"`; [`relative::now`]
+/// writes zero as `"now"` instead.
+pub mod relative {
+    use super::*;
+
+    /// Every recognized keyword; all of them mean zero.
+    pub const KEYWORDS: &[&str] = &["now", "immediately"];
+
+    pub fn parse(s: &str) -> Result<Duration, String> {
+        let t = s.trim();
+        if KEYWORDS.iter().any(|k| t.eq_ignore_ascii_case(k)) {
+            return Ok(Duration::ZERO);
+        }
+        parse_str(t)
+    }
+
+    pub fn serialize<S>(d: &Duration, s: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        super::serialize_human(d, s)
+    }
+    pub fn deserialize<'de, D>(d: D) -> Result<Duration, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        d.deserialize_any(DurVisitor { parse, ..DurVisitor::default() })
+    }
+
+    /// [`relative`](super), but zero serializes as `"now"`.
+    pub mod now {
+        use super::*;
+
+        pub fn serialize<S>(d: &Duration, s: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            match *d {
+                Duration::ZERO => s.serialize_str("now"),
+                _ => super::serialize(d, s),
+            }
+        }
+        pub fn deserialize<'de, D>(d: D) -> Result<Duration, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            super::deserialize(d)
+        }
+    }
+}
+
+/// `unit=number` pairs in one string: `"h=1 m=30 s=15"` is 1h 30m 15s.
+///
+/// Pairs are whitespace-separated and summed; keys are the standard units (`d`, `h`, `m`, `s`,
+/// `ms`, any case) and may appear once each. Numbers may have a `.` fraction (`"h=1.5"`).
+/// Serializes the same way, largest unit first, e.g. `"h=1 m=30"`; zero is `"s=0"`.
+pub mod kv_string {
+    use super::*;
+
+    pub fn to_kv_string(d: &Duration) -> String {
+        join_units(d, "=")
+    }
+
+    /// Nonzero components largest first, each `<unit><between><count>`; zero is `s<between>0`.
+    pub(super) fn join_units(d: &Duration, between: &str) -> String {
+        let mut ms = rounded_millis(d).min(MAX_MILLIS);
+        let mut out = String::new();
+        for (unit, &(key, _)) in HUMAN_UNITS.iter().zip(UNITS) {
+            let unit_ms = unit.nanos() / 1_000_000;
+            if ms >= unit_ms {
+                if !out.is_empty() {
+                    out.push(' ');
+                }
+                let _ = write!(out, "{key}{between}{}", ms / unit_ms);
+                ms %= unit_ms;
+            }
+        }
+        if out.is_empty() {
+            let _ = write!(out, "s{between}0");
+        }
+        out
+    }
+
+    pub fn parse(s: &str) -> Result<Duration, String> {
+        let mut seen = [false; 5];
+        let mut total = 0u128;
+        for pair in s.split_whitespace() {
+            let (key, value) = pair.split_once('=').ok_or_else(|| format!("expected unit=number, got '{pair}'"))?;
+            let (idx, nanos) = unit_value(key, value)?;
+            if std::mem::replace(&mut seen[idx], true) {
+                return Err(format!("duplicate unit '{key}'"));
+            }
+            total = total.checked_add(nanos).ok_or_else(|| "duration overflow".to_string())?;
+        }
+        if !seen.contains(&true) {
+            return Err("empty duration string".into());
+        }
+        duration_from_nanos(total).ok_or_else(|| "duration too large".into())
+    }
+
+    /// Position of `key` in [`UNITS`] and `value` of it in nanoseconds; `value` is digits with an
+    /// optional `.` fraction.
+    pub(super) fn unit_value(key: &str, value: &str) -> Result<(usize, u128), String> {
+        let Some(idx) = UNITS.iter().position(|(n, _)| unit_eq(key, n)) else {
+            return Err(format!("unknown unit '{key}' (this format takes d, h, m, s, ms)"));
+        };
+        let (whole, frac) = value.split_once('.').unwrap_or((value, ""));
+        let digits = |t: &str| !t.is_empty() && t.bytes().all(|b| b.is_ascii_digit());
+        if !digits(whole) || (value.len() > whole.len() && !digits(frac)) {
+            return Err(format!("invalid number '{value}' for unit '{key}'"));
+        }
+        let nanos_per_unit = UNITS[idx].1;
+        let (mut frac_n, mut scale) = (0u128, 1u128);
+        for b in frac.bytes().take(MAX_FRACTION_DIGITS) {
+            frac_n = frac_n * 10 + (b - b'0') as u128;
+            scale *= 10;
+        }
+        let nanos = whole
+            .parse::<u128>()
+            .ok()
+            .and_then(|n| n.checked_mul(nanos_per_unit))
+            .and_then(|v| v.checked_add((frac_n * nanos_per_unit + scale / 2) / scale))
+            .ok_or_else(|| "duration overflow".to_string())?;
+        Ok((idx, nanos))
+    }
+
+    pub fn serialize<S>(d: &Duration, s: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        s.serialize_str(&to_kv_string(d))
+    }
+    pub fn deserialize<'de, D>(d: D) -> Result<Duration, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        d.deserialize_any(DurVisitor { parse, ..DurVisitor::default() })
+    }
+}
+
+/// An 8-byte little-endian nanosecond count in standard base64: 1s is `"AMqaOwAAAAA="`.
+///
+/// For compact binary-in-JSON transports. The codec is built in (standard alphabet, `=` padding
+/// optional on input) and the decoded value must be exactly 8 bytes. Serializing durations above
+/// `u64::MAX` nanoseconds (about 584 years) is an error.
+pub mod base64_nanos {
+    use super::*;
+
+    const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+    pub fn to_base64_string(d: &Duration) -> Result<String, SerializeDurationError> {
+        let n = u64::try_from(d.as_nanos()).map_err(|_| SerializeDurationError::TooLarge)?;
+        let mut out = String::with_capacity(12);
+        for chunk in n.to_le_bytes().chunks(3) {
+            let b = [chunk[0], chunk.get(1).copied().unwrap_or(0), chunk.get(2).copied().unwrap_or(0)];
+            let sextets = [b[0] >> 2, (b[0] & 0x03) << 4 | b[1] >> 4, (b[1] & 0x0f) << 2 | b[2] >> 6, b[2] & 0x3f];
+            for (i, &s) in sextets.iter().enumerate() {
+                out.push(if i <= chunk.len() { ALPHABET[s as usize] as char } else { '=' });
+            }
+        }
+        Ok(out)
+    }
+
+    pub fn parse(s: &str) -> Result<Duration, String> {
+        let t = s.trim();
+        let body = t.trim_end_matches('=');
+        let pad = t.len() - body.len();
+        if pad > 2 || (pad > 0 && pad != (4 - body.len() % 4) % 4) {
+            return Err(format!("invalid base64 padding in '{t}'"));
+        }
+        let (mut bytes, mut bits, mut acc) = (Vec::with_capacity(8), 0u32, 0u32);
+        for c in body.bytes() {
+            let Some(v) = ALPHABET.iter().position(|&a| a == c) else {
+                return Err(format!("invalid base64 character '{}'", c as char));
+            };
+            acc = acc << 6 | v as u32;
+            bits += 6;
+            if bits >= 8 {
+                bits -= 8;
+                bytes.push((acc >> bits) as u8);
+                acc &= (1 << bits) - 1;
+            }
+        }
+        if bits >= 6 || acc != 0 {
+            return Err(format!("invalid base64 length or trailing bits in '{t}'"));
+        }
+        let bytes: [u8; 8] =
+            bytes.try_into().map_err(|b: Vec<u8>| format!("expected 8 bytes of nanoseconds, got {}", b.len()))?;
+        Ok(Duration::from_nanos(u64::from_le_bytes(bytes)))
+    }
+
+    pub fn serialize<S>(d: &Duration, s: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serialize_text(s, to_base64_string(d))
+    }
+    pub fn deserialize<'de, D>(d: D) -> Result<Duration, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct Base64Visitor;
+        impl<'de> Visitor<'de> for Base64Visitor {
+            type Value = Duration;
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("a base64 string of 8 little-endian nanosecond bytes")
+            }
+            fn visit_str<E: de::Error>(self, v: &str) -> Result<Duration, E> {
+                parse(v).map_err(E::custom)
+            }
+        }
+        d.deserialize_str(Base64Visitor)
+    }
+}
+
+/// Fixed-width `DDDDDHHMMSSmmm` keys, so lexical order is chronological order: 1d 2h 3m 4.25s is
+/// `"00001020304250"`.
+///
+/// Widths are 5 digits of days, 2 each of hours, minutes and seconds, and 3 of milliseconds, 14 in
+/// all; milliseconds are rounded half-up. The largest value is 99 999 days 23:59:59.999 (about
+/// 273 years); anything longer fails to serialize. Input must be exactly 14 digits, with hours below
+/// 24 and minutes and seconds below 60.
+pub mod sortable {
+    use super::*;
+
+    /// Length of every key.
+    pub const WIDTH: usize = 14;
+
+    const MAX_DAYS: u128 = 99_999;
+
+    pub fn to_sortable_string(d: &Duration) -> Result<String, SerializeDurationError> {
+        let ms = rounded_millis(d);
+        let (secs, sub_ms) = (ms / 1000, ms % 1000);
+        let (days, secs) = (secs / 86_400, secs % 86_400);
+        if days > MAX_DAYS {
+            return Err(SerializeDurationError::TooLarge);
+        }
+        let (h, m, s) = (secs / 3600, secs / 60 % 60, secs % 60);
+        Ok(format!("{days:05}{h:02}{m:02}{s:02}{sub_ms:03}"))
+    }
+
+    pub fn parse(s: &str) -> Result<Duration, String> {
+        let t = s.trim();
+        if t.len() != WIDTH || !t.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(format!("expected {WIDTH} digits as DDDDDHHMMSSmmm, got '{t}'"));
+        }
+        let field = |at: usize, width: usize| t[at..at + width].parse::<u64>().unwrap_or(0);
+        let (days, h, m, sec, ms) = (field(0, 5), field(5, 2), field(7, 2), field(9, 2), field(11, 3));
+        if h >= 24 || m >= 60 || sec >= 60 {
+            return Err(format!("clock field out of range in '{t}'"));
+        }
+        Ok(Duration::new(days * 86_400 + h * 3600 + m * 60 + sec, ms as u32 * 1_000_000))
+    }
+
+    pub fn serialize<S>(d: &Duration, s: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serialize_text(s, to_sortable_string(d))
+    }
+    pub fn deserialize<'de, D>(d: D) -> Result<Duration, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        // A plain string visitor: `DurVisitor` would read the all-digit key as a number.
+        struct SortableVisitor;
+        impl<'de> Visitor<'de> for SortableVisitor {
+            type Value = Duration;
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("a DDDDDHHMMSSmmm string")
+            }
+            fn visit_str<E: de::Error>(self, v: &str) -> Result<Duration, E> {
+                parse(v).map_err(E::custom)
+            }
+        }
+        d.deserialize_str(SortableVisitor)
+    }
+}
+
+/// Unit-first tokens: `"h1 m30"` is 1h 30m, the mirror of the usual `"1h 30m"`.
+///
+/// Each token is a standard unit (`d`, `h`, `m`, `s`, `ms`, any case) then a number, which may
+/// have a `.` fraction; whitespace between tokens is optional (`"h1m30"`) and tokens are summed.
+/// Every token must be unit-first, so `"1h"` and `"h1 30m"` are errors. Serializes the same way,
+/// e.g. `"h1 m30"`; zero is `"s0"`.
+pub mod unit_first {
+    use super::*;
+
+    pub fn to_unit_first_string(d: &Duration) -> String {
+        kv_string::join_units(d, "")
+    }
+
+    pub fn parse(s: &str) -> Result<Duration, String> {
+        let mut total = 0u128;
+        let mut rest = s.trim_start();
+        if rest.is_empty() {
+            return Err("empty duration string".into());
+        }
+        while !rest.is_empty() {
+            let pos = s.len() - rest.len();
+            let unit_len = rest.bytes().take_while(u8::is_ascii_alphabetic).count();
+            if unit_len == 0 {
+                return Err(format!("expected unit before number at position {pos} (unit-first, e.g. 'h1')"));
+            }
+            let (unit, after) = rest.split_at(unit_len);
+            let num_len = after.bytes().take_while(|b| b.is_ascii_digit() || *b == b'.').count();
+            if num_len == 0 {
+                return Err(format!("expected number after unit '{unit}' at position {pos}"));
+            }
+            let (_, nanos) = kv_string::unit_value(unit, &after[..num_len])?;
+            total = total.checked_add(nanos).ok_or_else(|| "duration overflow".to_string())?;
+            rest = after[num_len..].trim_start();
+        }
+        duration_from_nanos(total).ok_or_else(|| "duration too large".into())
+    }
+
+    pub fn serialize<S>(d: &Duration, s: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        s.serialize_str(&to_unit_first_string(d))
+    }
+    pub fn deserialize<'de, D>(d: D) -> Result<Duration, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        d.deserialize_any(DurVisitor { parse, ..DurVisitor::default() })
+    }
+}
+
+/// Unexpanded shell defaults: `"${TIMEOUT:-30s}"` parses the `30s` fallback.
+///
+/// Only the `${VAR:-FALLBACK}` form is recognized and the variable itself is never looked up;
+/// any other string goes through [`parse_str`] unchanged. Human output on serialize.
+pub mod env {
+    use super::*;
+
+    /// Parse `s`, or the `FALLBACK` part when `s` is `${VAR:-FALLBACK}`.
+    pub fn with_default(s: &str) -> Result<Duration, String> {
+        let t = s.trim();
+        match t.strip_prefix("${").and_then(|r| r.strip_suffix('}')) {
+            Some(inner) => {
+                let (_, fallback) =
+                    inner.split_once(":-").ok_or_else(|| format!("expected '${{VAR:-DEFAULT}}', got '{t}'"))?;
+                parse_str(fallback)
+            }
+            None => parse_str(s),
+        }
+    }
+
+    pub fn serialize<S>(d: &Duration, s: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        super::serialize_human(d, s)
+    }
+    pub fn deserialize<'de, D>(d: D) -> Result<Duration, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        d.deserialize_any(DurVisitor { parse: with_default, ..DurVisitor::default() })
+    }
+}
+
+/// Single-key envelopes: `{"value": "5m"}` or `{"duration": 300}`.
+///
+/// The inner value is parsed flexibly; any other key, or more than one key, is rejected.
+/// Serializes as `{"value": "<human>"}`.
+pub mod wrapped {
+    use serde::ser::SerializeMap;
+
+    use super::*;
+
+    const KEYS: &[&str] = &["value", "duration"];
+
+    pub fn serialize<S>(d: &Duration, s: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut map = s.serialize_map(Some(1))?;
+        map.serialize_entry("value", &to_human_string(d))?;
+        map.end()
+    }
+
+    pub fn deserialize<'de, D>(d: D) -> Result<Duration, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct WrappedVisitor;
+        impl<'de> Visitor<'de> for WrappedVisitor {
+            type Value = Duration;
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("a map with a single 'value' or 'duration' key")
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Duration, A::Error>
+            where
+                A: de::MapAccess<'de>,
+            {
+                let key = map.next_key::<String>()?.ok_or_else(|| de::Error::invalid_length(0, &self))?;
+                if !KEYS.contains(&key.as_str()) {
+                    return Err(de::Error::unknown_field(&key, KEYS));
+                }
+                let ExtDuration(dur) = map.next_value()?;
+                if map.next_key::<de::IgnoredAny>()?.is_some() {
+                    return Err(de::Error::custom("expected a single key in duration wrapper"));
+                }
+                Ok(dur)
+            }
+        }
+        d.deserialize_map(WrappedVisitor)
+    }
+}
+
+/// Canonical unit abbreviations, the ones the human output writes, as `(unit, nanoseconds per
+/// unit)`. [`parse_str`] accepts these and more spellings (case-insensitive); [`units`] lists all.
+pub const UNITS: &[(&str, u128)] = &[
+    ("d", Unit::Days.nanos()),
+    ("h", Unit::Hours.nanos()),
+    ("m", Unit::Minutes.nanos()),
+    ("s", Unit::Seconds.nanos()),
+    ("ms", Unit::Millis.nanos()),
+];
+
+/// How numbers are written inside duration strings.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum NumberFormat {
+    /// `.` is the decimal point: `"1.5h"`, `"0.25s"`; whole numbers work as always.
+    #[default]
+    Plain,
+    /// `,` is the decimal point: `"1,5s"` is 1.5 seconds.
+    ///
+    /// A `,` is then always part of a number, never a token separator.
+    DecimalComma,
+    /// `.` groups thousands and is stripped: `"1.000s"` is 1000 seconds, `"1.000.000ms"` is
+    /// 1000 seconds.
+    ///
+    /// There is no decimal point in this mode. Every group after a `.` must have exactly three
+    /// digits, so a decimal-looking `"1.5s"` is an error rather than 15 seconds.
+    ThousandsDot,
+    /// `,` groups thousands and is stripped: `"1,250ms"` is 1250 milliseconds; `.` stays the
+    /// decimal point, so `"1,000.5s"` is 1000.5 seconds.
+    ///
+    /// A `,` is then always part of a number, never a token separator. Groups follow the same
+    /// three-digit rule as [`ThousandsDot`](NumberFormat::ThousandsDot).
+    ThousandsComma,
+}
+
+impl NumberFormat {
+    fn decimal_separator(self) -> Option<u8> {
+        match self {
+            NumberFormat::Plain | NumberFormat::ThousandsComma => Some(b'.'),
+            NumberFormat::DecimalComma => Some(b','),
+            NumberFormat::ThousandsDot => None,
+        }
+    }
+
+    fn group_separator(self) -> Option<u8> {
+        match self {
+            NumberFormat::ThousandsDot => Some(b'.'),
+            NumberFormat::ThousandsComma => Some(b','),
+            NumberFormat::Plain | NumberFormat::DecimalComma => None,
+        }
+    }
+}
+
+/// Languages whose unit words [`parse_str_localized`] understands on top of [`units`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Locale {
+    English,
+    Spanish,
+    French,
+    German,
+}
+
+impl Locale {
+    /// Unit words for this language (singular and plural), as `(word, nanoseconds per unit)`.
+    pub const fn words(self) -> &'static [(&'static str, u128)] {
+        const D: u128 = Unit::Days.nanos();
+        const H: u128 = Unit::Hours.nanos();
+        const M: u128 = Unit::Minutes.nanos();
+        const S: u128 = Unit::Seconds.nanos();
+        const MS: u128 = Unit::Millis.nanos();
+        match self {
+            Locale::English => &[
+                ("day", D),
+                ("days", D),
+                ("hour", H),
+                ("hours", H),
+                ("minute", M),
+                ("minutes", M),
+                ("second", S),
+                ("seconds", S),
+                ("millisecond", MS),
+                ("milliseconds", MS),
+            ],
+            Locale::Spanish => &[
+                ("día", D),
+                ("días", D),
+                ("dia", D),
+                ("dias", D),
+                ("hora", H),
+                ("horas", H),
+                ("minuto", M),
+                ("minutos", M),
+                ("segundo", S),
+                ("segundos", S),
+                ("milisegundo", MS),
+                ("milisegundos", MS),
+            ],
+            Locale::French => &[
+                ("jour", D),
+                ("jours", D),
+                ("heure", H),
+                ("heures", H),
+                ("minute", M),
+                ("minutes", M),
+                ("seconde", S),
+                ("secondes", S),
+                ("milliseconde", MS),
+                ("millisecondes", MS),
+            ],
+            Locale::German => &[
+                ("tag", D),
+                ("tage", D),
+                ("tagen", D),
+                ("stunde", H),
+                ("stunden", H),
+                ("minute", M),
+                ("minuten", M),
+                ("sekunde", S),
+                ("sekunden", S),
+                ("millisekunde", MS),
+                ("millisekunden", MS),
+            ],
+        }
+    }
+}
+
+/// Knobs for [`parse_str_with`]; the default is exactly [`parse_str`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParseOptions {
+    number_format: NumberFormat,
+    locale: Option<Locale>,
+    reject_leading_zeros: bool,
+    plural_units: bool,
+    overflow: OverflowPolicy,
+    max_len: usize,
+}
+
+impl Default for ParseOptions {
+    fn default() -> Self {
+        ParseOptions::new()
+    }
+}
+
+impl ParseOptions {
+    pub const fn new() -> Self {
+        ParseOptions {
+            number_format: NumberFormat::Plain,
+            locale: None,
+            reject_leading_zeros: false,
+            plural_units: false,
+            overflow: OverflowPolicy::Error,
+            max_len: DEFAULT_MAX_LEN,
+        }
+    }
+
+    /// Numeric style; the modes are mutually exclusive by construction.
+    pub const fn number_format(mut self, number_format: NumberFormat) -> Self {
+        self.number_format = number_format;
+        self
+    }
+
+    /// Also accept the unit words of `locale` (the abbreviations in [`UNITS`] always work).
+    pub const fn locale(mut self, locale: Locale) -> Self {
+        self.locale = Some(locale);
+        self
+    }
+
+    /// Reject multi-digit numbers starting with `0` (`"007s"`, `"01m"`); a lone `"0"` stays valid.
+    /// Off by default; strict schemas use it to avoid octal look-alikes.
+    pub const fn reject_leading_zeros(mut self, reject: bool) -> Self {
+        self.reject_leading_zeros = reject;
+        self
+    }
+
+    /// Accept a plural `s` after `h` and `d` (`"3hs"`, `"2ds"`). Only those two: `ms` already means
+    /// milliseconds, so `m` cannot take one, and `ss` is not a unit. Default: off.
+    pub const fn plural_units(mut self, enabled: bool) -> Self {
+        self.plural_units = enabled;
+        self
+    }
+
+    /// Reject strings longer than `bytes` before scanning them (default [`DEFAULT_MAX_LEN`]), so
+    /// untrusted configs cannot feed the parser pathological input.
+    pub const fn max_len(mut self, bytes: usize) -> Self {
+        self.max_len = bytes;
+        self
+    }
+
+    /// Totals past `Duration::MAX` (default [`OverflowPolicy::Error`]). With `Wrap` the result is the
+    /// exact total modulo `Duration::MAX` + 1ns, however large the numbers in the string.
+    pub const fn overflow(mut self, policy: OverflowPolicy) -> Self {
+        self.overflow = policy;
+        self
+    }
+}
+
+/// Every unit spelling `humantime` accepts, so its strings parse unchanged (except `M` for months:
+/// matching is case-insensitive, so `M` stays minutes; spell out `month`).
+const HUMANTIME_UNITS: &[(&str, u128)] = &[
+    ("nanos", 1),
+    ("nsec", 1),
+    ("ns", 1),
+    ("usec", 1_000),
+    ("us", 1_000),
+    ("µs", 1_000),
+    ("μs", 1_000),
+    ("millis", Unit::Millis.nanos()),
+    ("msec", Unit::Millis.nanos()),
+    ("seconds", Unit::Seconds.nanos()),
+    ("second", Unit::Seconds.nanos()),
+    ("secs", Unit::Seconds.nanos()),
+    ("sec", Unit::Seconds.nanos()),
+    ("minutes", Unit::Minutes.nanos()),
+    ("minute", Unit::Minutes.nanos()),
+    ("mins", Unit::Minutes.nanos()),
+    ("min", Unit::Minutes.nanos()),
+    ("hours", Unit::Hours.nanos()),
+    ("hour", Unit::Hours.nanos()),
+    ("hrs", Unit::Hours.nanos()),
+    ("hr", Unit::Hours.nanos()),
+    ("days", Unit::Days.nanos()),
+    ("day", Unit::Days.nanos()),
+    ("weeks", 7 * Unit::Days.nanos()),
+    ("week", 7 * Unit::Days.nanos()),
+    ("wks", 7 * Unit::Days.nanos()),
+    ("wk", 7 * Unit::Days.nanos()),
+    ("w", 7 * Unit::Days.nanos()),
+    // 30.44 and 365.25 days, as in `humantime`
+    ("months", 2_630_016 * Unit::Seconds.nanos()),
+    ("month", 2_630_016 * Unit::Seconds.nanos()),
+    ("years", 31_557_600 * Unit::Seconds.nanos()),
+    ("year", 31_557_600 * Unit::Seconds.nanos()),
+    ("yrs", 31_557_600 * Unit::Seconds.nanos()),
+    ("yr", 31_557_600 * Unit::Seconds.nanos()),
+    ("y", 31_557_600 * Unit::Seconds.nanos()),
+];
+
+/// Every unit spelling [`parse_str`] accepts, as `(unit, nanoseconds per unit)`: [`UNITS`]
+/// followed by the longer and `humantime` spellings (`"hours"`, `"µs"`, `"w"`, ...). Matching is
+/// case-insensitive; [`Locale`] words are not included.
+pub fn units() -> impl Iterator<Item = (&'static str, u128)> {
+    UNITS.iter().chain(HUMANTIME_UNITS).copied()
+}
+
+/// Default input limit of [`ParseOptions::max_len`], far above any real duration string.
+pub const DEFAULT_MAX_LEN: usize = 256;
+
+/// Fraction digits beyond this are ignored: they are far below a nanosecond for every unit.
+const MAX_FRACTION_DIGITS: usize = 18;
+
+pub fn parse_str(s: &str) -> Result<Duration, String> {
+    parse_str_with(s, &ParseOptions::new())
+}
+
+/// The total [`parse_str`] computes, in nanoseconds, before it becomes a `Duration`: sums past
+/// `Duration::MAX` are returned as they are, and only a total beyond `u128` is an error.
+///
+/// ```
+/// use serde_ext_duration::parse_str_nanos;
+///
+/// assert_eq!(parse_str_nanos("1s 5ns").unwrap(), 1_000_000_005);
+/// let huge = parse_str_nanos("18446744073709551615s 1s").unwrap();
+/// assert_eq!(huge, (u64::MAX as u128 + 1) * 1_000_000_000);
+/// assert!(serde_ext_duration::parse_str("18446744073709551615s 1s").is_err());
+/// ```
+pub fn parse_str_nanos(s: &str) -> Result<u128, ParseDurationError> {
+    total_nanos_with(s, &ParseOptions::new())
+        .and_then(|total| total.ok_or_else(|| "duration overflow".to_string()))
+        .map_err(|message| ParseDurationError { input: s.to_string(), message })
+}
+
+/// [`parse_str`] that also understands unit words of `locale`, e.g. `"1 Stunde 30 Minuten"`.
+pub fn parse_str_localized(s: &str, locale: Locale) -> Result<Duration, String> {
+    parse_str_with(s, &ParseOptions::new().locale(locale))
+}
+
+/// A parser like [`parse_str`] that maps empty and whitespace-only strings to `default`
+/// instead of failing with "empty duration string"; see [`empty_default`] for the serde side.
+pub fn with_default_on_empty(default: Duration) -> impl Fn(&str) -> Result<Duration, String> {
+    move |s| if s.trim().is_empty() { Ok(default) } else { parse_str(s) }
+}
+
+/// A parser like [`parse_str`] that rejects anything outside `allowed`, listing the valid
+/// options: `"2m is not allowed (use one of 1m, 5m, 15m)"`. See [`deserialize_one_of`] for the
+/// serde side.
+pub fn one_of(allowed: &[Duration]) -> impl Fn(&str) -> Result<Duration, String> + '_ {
+    move |s| check_one_of(parse_str(s)?, allowed)
+}
+
+fn check_one_of(d: Duration, allowed: &[Duration]) -> Result<Duration, String> {
+    if allowed.contains(&d) {
+        return Ok(d);
+    }
+    let options: Vec<String> = allowed.iter().map(to_human_string).collect();
+    Err(format!("{} is not allowed (use one of {})", to_human_string(&d), options.join(", ")))
+}
+
+/// [`parse_str`] with non-default [`ParseOptions`].
+pub fn parse_str_with(s: &str, opts: &ParseOptions) -> Result<Duration, String> {
+    match total_nanos_with(s, opts)?.and_then(duration_from_nanos) {
+        Some(d) => Ok(d),
+        None if opts.overflow == OverflowPolicy::Saturate => Ok(Duration::MAX),
+        None => Err("duration too large".into()),
+    }
+}
+
+/// Total nanoseconds of `s`, not yet limited to `Duration::MAX`; `None` when the sum overflowed
+/// `u128`, which only [`OverflowPolicy::Saturate`] lets through.
+fn total_nanos_with(s: &str, opts: &ParseOptions) -> Result<Option<u128>, String> {
+    if s.len() > opts.max_len {
+        return Err(format!("duration string too long ({} bytes, limit {})", s.len(), opts.max_len));
+    }
+    // A lone zero needs no unit (as in `humantime`).
+    if s.trim() == "0" {
+        return Ok(Some(0));
+    }
+    let wrap = opts.overflow == OverflowPolicy::Wrap;
+    // `None` once the total has overflowed, which only `Saturate` lets through to the end.
+    let mut total_nanos: Option<u128> = Some(0);
+    let mut token_count: u32 = 0;
+    let bytes = s.as_bytes();
+    let len = bytes.len();
+    let mut i = 0;
+
+    while i < len {
+        while i < len && bytes[i].is_ascii_whitespace() {
+            i += 1;
+        }
+        if i >= len {
+            break;
+        }
+        let start_num = i;
+        while i < len && bytes[i].is_ascii_digit() {
+            i += 1;
+        }
+        if i == start_num {
+            return Err(format!("expected number at position {start_num}"));
+        }
+        if opts.reject_leading_zeros && i - start_num > 1 && bytes[start_num] == b'0' {
+            return Err(format!("leading zero in number at position {start_num}"));
+        }
+        if let Some(sep) = opts.number_format.group_separator() {
+            while i + 1 < len && bytes[i] == sep && bytes[i + 1].is_ascii_digit() {
+                i += 1;
+                let start_group = i;
+                while i < len && bytes[i].is_ascii_digit() {
+                    i += 1;
+                }
+                if i - start_group != 3 {
+                    return Err(format!("digit group at position {start_group} must have 3 digits"));
+                }
+            }
+        }
+        let digits = s[start_num..i].bytes().filter(u8::is_ascii_digit);
+        let n: Option<u128> = if wrap {
+            Some(digits.fold(0, |acc, b| (acc * 10 + (b - b'0') as u128) % WRAP_MODULUS))
+        } else {
+            digits.map(|b| (b - b'0') as u128).try_fold(0u128, |acc, d| acc.checked_mul(10)?.checked_add(d))
+        };
+        if n.is_none() && opts.overflow == OverflowPolicy::Error {
+            return Err(format!("invalid number at position {start_num}"));
+        }
+        // Optional fraction as numerator / denominator, only when a decimal separator is enabled.
+        let (mut frac, mut scale) = (0u128, 1u128);
+        if let Some(sep) = opts.number_format.decimal_separator() {
+            if i + 1 < len && bytes[i] == sep && bytes[i + 1].is_ascii_digit() {
+                i += 1;
+                let start_frac = i;
+                while i < len && bytes[i].is_ascii_digit() {
+                    if i - start_frac < MAX_FRACTION_DIGITS {
+                        frac = frac * 10 + (bytes[i] - b'0') as u128;
+                        scale *= 10;
+                    }
+                    i += 1;
+                }
+            }
+        }
+        while i < len && bytes[i].is_ascii_whitespace() {
+            i += 1;
+        }
+        let start_unit = i;
+        while let Some(c) = s[i..].chars().next().filter(|c| c.is_alphabetic()) {
+            i += c.len_utf8();
+        }
+        if start_unit == i {
+            return Err(format!("expected unit after number at position {}", start_num));
+        }
+        // Units are matched case-insensitively in place; the success path never allocates.
+        let unit = &s[start_unit..i];
+        let words = opts.locale.map_or(&[][..], Locale::words);
+        let lookup = |name: &str| {
+            let mut all = UNITS.iter().chain(HUMANTIME_UNITS).chain(words);
+            all.find(|(n, _)| unit_eq(name, n)).map(|&(_, nanos)| nanos)
+        };
+        let plural = || match unit.as_bytes() {
+            [c, b's' | b'S'] if opts.plural_units && matches!(c.to_ascii_lowercase(), b'h' | b'd') => {
+                lookup(&unit[..1])
+            }
+            _ => None,
+        };
+        let nanos_per_unit = match lookup(unit).or_else(plural) {
+            Some(nanos) => nanos,
+            None => {
+                return Err(format!(
+                    "unknown unit '{}' (e.g. d, h, m, s, ms, hours, µs; see `units()`)",
+                    unit.to_lowercase()
+                ))
+            }
+        };
+        let frac_nanos = (frac * nanos_per_unit + scale / 2) / scale;
+        total_nanos = if wrap {
+            total_nanos.zip(n).map(|(t, n)| (t + mul_wrapping(n, nanos_per_unit) + frac_nanos) % WRAP_MODULUS)
+        } else {
+            n.and_then(|n| n.checked_mul(nanos_per_unit))
+                .and_then(|whole| whole.checked_add(frac_nanos))
+                .zip(total_nanos)
+                .and_then(|(inc, t)| t.checked_add(inc))
+        };
+        if total_nanos.is_none() && opts.overflow == OverflowPolicy::Error {
+            return Err("duration overflow".into());
+        }
+        token_count += 1;
+        while i < len && bytes[i].is_ascii_whitespace() {
+            i += 1;
+        }
+    }
+    if token_count == 0 {
+        return Err("empty duration string".into());
     }
-    deserializer.deserialize_any(DurVisitor)
+    Ok(total_nanos)
 }
 
-/// Root `serialize`: human format (so `#[serde(with = "serde_ext_duration")]` works).
-pub fn serialize<S>(dur: &Duration, serializer: S) -> Result<S::Ok, S::Error>
-where
-    S: Serializer,
-{
-    serialize_human(dur, serializer)
-}
-
-pub fn serialize_human<S>(dur: &Duration, serializer: S) -> Result<S::Ok, S::Error>
-where
-    S: Serializer,
-{
-    serializer.serialize_str(&to_human_string(dur))
+/// `input` equals the lowercase table name `name` once lowercased, compared char by char.
+fn unit_eq(input: &str, name: &str) -> bool {
+    input.chars().flat_map(char::to_lowercase).eq(name.chars())
 }
 
-pub fn serialize_secs<S>(dur: &Duration, serializer: S) -> Result<S::Ok, S::Error>
-where
-    S: Serializer,
-{
-    serializer.serialize_u64(dur.as_secs())
-}
+/// `Duration::MAX` + 1ns, the modulus of [`OverflowPolicy::Wrap`] for durations.
+const WRAP_MODULUS: u128 = MAX_NANOS + 1;
 
-pub fn serialize_millis<S>(dur: &Duration, serializer: S) -> Result<S::Ok, S::Error>
-where
-    S: Serializer,
-{
-    let ms_total = (dur.as_secs() as u128) * 1000 + ((dur.subsec_nanos() as u128 + 500_000) / 1_000_000);
-    if ms_total > u64::MAX as u128 {
-        return Err(serde::ser::Error::custom("duration too large"));
+/// `a * b` modulo [`WRAP_MODULUS`] without overflowing `u128` (the modulus is below 2^95).
+fn mul_wrapping(a: u128, mut b: u128) -> u128 {
+    let (mut a, mut r) = (a % WRAP_MODULUS, 0);
+    while b > 0 {
+        if b & 1 == 1 {
+            r = (r + a) % WRAP_MODULUS;
+        }
+        a = (a << 1) % WRAP_MODULUS;
+        b >>= 1;
     }
-    serializer.serialize_u64(ms_total as u64)
-}
-
-pub fn serialize_secs_f64_ms<S>(dur: &Duration, serializer: S) -> Result<S::Ok, S::Error>
-where
-    S: Serializer,
-{
-    let f = dur.as_secs() as f64 + (dur.subsec_millis() as f64) / 1000.0;
-    let f = (f * 1000.0).round() / 1000.0; // 3 decimals
-    serializer.serialize_f64(f)
+    r
 }
 
-/// Build a canonical human string out of a `Duration` with units d/h/m/s/ms.
-fn to_human_string(dur: &Duration) -> String {
-    // Round to nearest millisecond, then decompose.
-    let mut ms_total: u128 = (dur.as_secs() as u128) * 1000 + ((dur.subsec_nanos() as u128 + 500_000) / 1_000_000);
-
-    if ms_total == 0 {
-        return "0s".to_string();
-    }
-
-    let day = 86_400_000u128;
-    let hour = 3_600_000u128;
-    let minute = 60_000u128;
-    let second = 1_000u128;
+/// `.` as a thousands separator (`"1.000s"` = 1000s), see [`NumberFormat::ThousandsDot`].
+///
+/// Human output on serialize, which never groups digits.
+pub mod thousands_dot {
+    use super::*;
 
-    let mut parts = Vec::new();
+    const OPTS: ParseOptions = ParseOptions::new().number_format(NumberFormat::ThousandsDot);
 
-    let d = ms_total / day;
-    ms_total %= day;
-    if d > 0 {
-        parts.push(format!("{d}d"));
-    }
-    let h = ms_total / hour;
-    ms_total %= hour;
-    if h > 0 {
-        parts.push(format!("{h}h"));
-    }
-    let m = ms_total / minute;
-    ms_total %= minute;
-    if m > 0 {
-        parts.push(format!("{m}m"));
+    pub fn parse(s: &str) -> Result<Duration, String> {
+        parse_str_with(s, &OPTS)
     }
-    let s = ms_total / second;
-    ms_total %= second;
-    if s > 0 {
-        parts.push(format!("{s}s"));
-    }
-    let ms = ms_total;
-    if ms > 0 {
-        parts.push(format!("{ms}ms"));
-    }
-
-    parts.join(" ")
-}
 
-/// Human: `serialize` + flexible `deserialize`.
-pub mod human {
-    use super::*;
     pub fn serialize<S>(d: &Duration, s: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
@@ -177,121 +3842,233 @@ pub mod human {
     where
         D: Deserializer<'de>,
     {
-        super::deserialize(d)
+        d.deserialize_any(DurVisitor { parse, ..DurVisitor::default() })
     }
 }
 
-/// Seconds (u64) on output; flexible input on deserialize.
-pub mod secs {
+/// `,` as a thousands separator (`"1,250ms"` = 1250ms), see [`NumberFormat::ThousandsComma`].
+///
+/// Human output on serialize, which never groups digits.
+pub mod thousands_comma {
     use super::*;
+
+    const OPTS: ParseOptions = ParseOptions::new().number_format(NumberFormat::ThousandsComma);
+
+    pub fn parse(s: &str) -> Result<Duration, String> {
+        parse_str_with(s, &OPTS)
+    }
+
     pub fn serialize<S>(d: &Duration, s: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
     {
-        super::serialize_secs(d, s)
+        super::serialize_human(d, s)
     }
     pub fn deserialize<'de, D>(d: D) -> Result<Duration, D::Error>
     where
         D: Deserializer<'de>,
     {
-        super::deserialize(d)
+        d.deserialize_any(DurVisitor { parse, ..DurVisitor::default() })
     }
 }
 
-/// Milliseconds (u64) on output; flexible input on deserialize.
-pub mod millis {
+/// `,` as the decimal point (`"1,5s"` = 1.5s), see [`NumberFormat::DecimalComma`].
+///
+/// Human output on serialize, which never contains a fraction.
+pub mod decimal_comma {
     use super::*;
+
+    const OPTS: ParseOptions = ParseOptions::new().number_format(NumberFormat::DecimalComma);
+
+    pub fn parse(s: &str) -> Result<Duration, String> {
+        parse_str_with(s, &OPTS)
+    }
+
     pub fn serialize<S>(d: &Duration, s: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
     {
-        super::serialize_millis(d, s)
+        super::serialize_human(d, s)
     }
     pub fn deserialize<'de, D>(d: D) -> Result<Duration, D::Error>
     where
         D: Deserializer<'de>,
     {
-        super::deserialize(d)
+        d.deserialize_any(DurVisitor { parse, ..DurVisitor::default() })
     }
 }
 
-/// Seconds as f64 (ms precision) on output; flexible input on deserialize.
-pub mod secs_f64_ms {
-    use super::*;
-    pub fn serialize<S>(d: &Duration, s: S) -> Result<S::Ok, S::Error>
-    where
-        S: Serializer,
-    {
-        super::serialize_secs_f64_ms(d, s)
+// ===== Utilities =====
+
+/// Returned when a duration computation would exceed `Duration::MAX`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OverflowError;
+
+impl fmt::Display for OverflowError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("duration overflow")
     }
-    pub fn deserialize<'de, D>(d: D) -> Result<Duration, D::Error>
-    where
-        D: Deserializer<'de>,
-    {
-        super::deserialize(d)
+}
+
+impl std::error::Error for OverflowError {}
+
+/// Running total of parsed durations, e.g. across a stream of records.
+///
+/// Unlike `+=` on `Duration`, [`add`](Accumulator::add) never panics: on overflow it reports
+/// [`OverflowError`] and leaves the total unchanged.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Accumulator {
+    total: Duration,
+}
+
+impl Accumulator {
+    pub const fn new() -> Self {
+        Accumulator { total: Duration::ZERO }
+    }
+
+    pub fn add(&mut self, d: &Duration) -> Result<(), OverflowError> {
+        self.total = self.total.checked_add(*d).ok_or(OverflowError)?;
+        Ok(())
+    }
+
+    pub fn total(&self) -> Duration {
+        self.total
     }
 }
 
-pub fn parse_str(s: &str) -> Result<Duration, String> {
-    let mut total_ms: u128 = 0;
-    let mut token_count: u32 = 0;
-    let bytes = s.as_bytes();
-    let len = bytes.len();
-    let mut i = 0;
-    let is_alpha = |b: u8| (b as char).is_ascii_alphabetic();
+/// Round `dur` to the nearest multiple of `granularity`, halves rounding up.
+///
+/// With 5 minutes, `7m` → `5m` and `8m` (or `7m 30s`) → `10m`. A zero granularity returns `dur`
+/// unchanged; if rounding up would pass `Duration::MAX`, the lower multiple is returned instead.
+pub fn round_to(dur: Duration, granularity: Duration) -> Duration {
+    let g = granularity.as_nanos();
+    if g == 0 {
+        return dur;
+    }
+    let n = dur.as_nanos();
+    let down = n - n % g;
+    let nearest = if n % g >= g - g / 2 { down + g } else { down };
+    duration_from_nanos(nearest).or_else(|| duration_from_nanos(down)).unwrap_or(Duration::MAX)
+}
 
-    while i < len {
-        while i < len && bytes[i].is_ascii_whitespace() {
-            i += 1;
-        }
-        if i >= len {
-            break;
-        }
-        let start_num = i;
-        while i < len && bytes[i].is_ascii_digit() {
-            i += 1;
-        }
-        if i == start_num {
-            return Err(format!("expected number at position {start_num}"));
-        }
-        let n: u128 = s[start_num..i].parse().map_err(|_| format!("invalid number at position {start_num}"))?;
-        while i < len && bytes[i].is_ascii_whitespace() {
-            i += 1;
-        }
-        let start_unit = i;
-        while i < len && is_alpha(bytes[i]) {
-            i += 1;
-        }
-        if start_unit == i {
-            return Err(format!("expected unit after number at position {}", start_num));
-        }
-        let unit = s[start_unit..i].to_ascii_lowercase();
-        let ms_per_unit: u128 = match unit.as_str() {
-            "d" => 86_400_000,
-            "h" => 3_600_000,
-            "ms" => 1,
-            "m" => 60_000,
-            "s" => 1_000,
-            _ => return Err(format!("unknown unit '{unit}' (use d, h, m, s, ms)")),
-        };
-        let inc = n.checked_mul(ms_per_unit).ok_or_else(|| "duration overflow".to_string())?;
-        total_ms = total_ms.checked_add(inc).ok_or_else(|| "duration overflow".to_string())?;
-        token_count += 1;
-        while i < len && bytes[i].is_ascii_whitespace() {
-            i += 1;
+/// Why [`scale`] rejected its input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScaleError {
+    /// The factor was NaN, infinite or negative.
+    InvalidFactor,
+    /// The scaled value exceeds `Duration::MAX`.
+    Overflow,
+}
+
+impl fmt::Display for ScaleError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ScaleError::InvalidFactor => f.write_str("scale factor must be finite and non-negative"),
+            ScaleError::Overflow => f.write_str("duration overflow"),
         }
     }
-    if token_count == 0 {
-        return Err("empty duration string".into());
+}
+
+impl std::error::Error for ScaleError {}
+
+/// `dur * factor` to the nearest nanosecond, e.g. a base timeout times a configured `1.5`.
+///
+/// Unlike `Duration::mul_f64` this never panics. The product is computed in `f64`, so very long
+/// durations (beyond ~104 days) are exact only to `f64` precision.
+pub fn scale(dur: Duration, factor: f64) -> Result<Duration, ScaleError> {
+    if !factor.is_finite() || factor < 0.0 {
+        return Err(ScaleError::InvalidFactor);
+    }
+    let nanos = (dur.as_nanos() as f64 * factor).round();
+    if nanos > MAX_NANOS as f64 {
+        return Err(ScaleError::Overflow);
+    }
+    duration_from_nanos(nanos as u128).ok_or(ScaleError::Overflow)
+}
+
+/// Index of the first boundary `>= dur`, or `boundaries.len()` when `dur` is above them all, as
+/// for Prometheus-style `le` histogram buckets. `boundaries` must be sorted ascending.
+///
+/// ```
+/// use serde_ext_duration::{bucket, bucket_label};
+/// use std::time::Duration;
+///
+/// let bounds = [Duration::from_millis(100), Duration::from_millis(250), Duration::from_secs(1)];
+/// assert_eq!(bucket(Duration::from_millis(250), &bounds), 1);
+/// assert_eq!(bucket_label(&bounds, 1), "250ms");
+/// assert_eq!(bucket_label(&bounds, bucket(Duration::from_secs(5), &bounds)), "+Inf");
+/// ```
+pub fn bucket(dur: Duration, boundaries: &[Duration]) -> usize {
+    boundaries.partition_point(|b| *b < dur)
+}
+
+/// Label for bucket `index` from [`bucket`]: its upper bound as human output, or `"+Inf"` for the
+/// overflow bucket.
+///
+/// # Panics
+/// If `index > boundaries.len()`.
+pub fn bucket_label(boundaries: &[Duration], index: usize) -> String {
+    assert!(index <= boundaries.len(), "bucket index {index} out of range for {} boundaries", boundaries.len());
+    match boundaries.get(index) {
+        Some(b) => to_human_string(b),
+        None => "+Inf".into(),
+    }
+}
+
+/// Why one string failed [`parse_str`], as reported by [`validate_all`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseDurationError {
+    input: String,
+    message: String,
+}
+
+impl ParseDurationError {
+    /// The string that failed to parse.
+    pub fn input(&self) -> &str {
+        &self.input
+    }
+
+    /// The parser's message, e.g. `"expected unit after number at position 0"`.
+    pub fn message(&self) -> &str {
+        &self.message
     }
-    if total_ms > u64::MAX as u128 {
-        return Err("duration too large".into());
+}
+
+impl fmt::Display for ParseDurationError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "invalid duration '{}': {}", self.input, self.message)
     }
-    Ok(Duration::from_millis(total_ms as u64))
+}
+
+impl std::error::Error for ParseDurationError {}
+
+/// [`parse_str`] every input and keep every result with its index, so a config linter can report
+/// all bad durations at once instead of stopping at the first.
+///
+/// ```
+/// use serde_ext_duration::validate_all;
+///
+/// let bad: Vec<_> = validate_all(["5s", "5x", "1h", ""])
+///     .into_iter()
+///     .filter_map(|(i, r)| r.err().map(|e| (i, e.to_string())))
+///     .collect();
+/// let msg = "invalid duration '5x': unknown unit 'x' (e.g. d, h, m, s, ms, hours, µs; see `units()`)";
+/// assert_eq!(bad[0], (1, msg.to_string()));
+/// assert_eq!(bad[1].0, 3);
+/// ```
+pub fn validate_all<'a, I>(inputs: I) -> Vec<(usize, Result<Duration, ParseDurationError>)>
+where
+    I: IntoIterator<Item = &'a str>,
+{
+    inputs
+        .into_iter()
+        .enumerate()
+        .map(|(i, s)| (i, parse_str(s).map_err(|message| ParseDurationError { input: s.to_string(), message })))
+        .collect()
 }
 
 // ===== Optional newtype (defaults to human on Serialize) =====
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct ExtDuration(pub Duration);
 
 impl<'de> Deserialize<'de> for ExtDuration {
@@ -311,6 +4088,218 @@ impl Serialize for ExtDuration {
     }
 }
 
+/// Saturating total: `durations.iter().sum::<ExtDuration>()` stops at `Duration::MAX` instead of
+/// panicking like `Sum for Duration`. Use [`Accumulator`] to detect the overflow instead.
+impl std::iter::Sum<Duration> for ExtDuration {
+    fn sum<I: Iterator<Item = Duration>>(iter: I) -> Self {
+        ExtDuration(iter.fold(Duration::ZERO, Duration::saturating_add))
+    }
+}
+
+impl<'a> std::iter::Sum<&'a Duration> for ExtDuration {
+    fn sum<I: Iterator<Item = &'a Duration>>(iter: I) -> Self {
+        iter.copied().sum()
+    }
+}
+
+impl std::iter::Sum for ExtDuration {
+    fn sum<I: Iterator<Item = ExtDuration>>(iter: I) -> Self {
+        iter.map(|d| d.0).sum()
+    }
+}
+
+impl PartialEq<Duration> for ExtDuration {
+    fn eq(&self, other: &Duration) -> bool {
+        self.0 == *other
+    }
+}
+
+impl PartialEq<ExtDuration> for Duration {
+    fn eq(&self, other: &ExtDuration) -> bool {
+        *self == other.0
+    }
+}
+
+impl std::ops::Deref for ExtDuration {
+    type Target = Duration;
+    fn deref(&self) -> &Duration {
+        &self.0
+    }
+}
+
+impl AsRef<Duration> for ExtDuration {
+    fn as_ref(&self) -> &Duration {
+        &self.0
+    }
+}
+
+/// A duration that remembers how it was written, for tools that rewrite configs without
+/// reformatting untouched fields: `"90m"` serializes back as `"90m"`, not `"1h 30m"`.
+///
+/// `value` is authoritative. `original` is re-parsed on serialize and emitted only if it still
+/// equals `value`; after `value` changes, the canonical human form is written instead. Only strings
+/// deserialize (a number has no spelling to keep); use [`ExtDuration`] for numeric input.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct RawDuration {
+    pub original: String,
+    pub value: Duration,
+}
+
+impl RawDuration {
+    /// Parse `s` with the flexible string rules (numeric strings included), keeping it verbatim.
+    pub fn parse(s: &str) -> Result<Self, String> {
+        let value = DurVisitor::default().visit_str::<de::value::Error>(s).map_err(|e| e.to_string())?;
+        Ok(RawDuration { original: s.to_string(), value })
+    }
+}
+
+impl From<Duration> for RawDuration {
+    fn from(value: Duration) -> Self {
+        RawDuration { original: to_human_string(&value), value }
+    }
+}
+
+impl<'de> Deserialize<'de> for RawDuration {
+    fn deserialize<D>(d: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = String::deserialize(d)?;
+        RawDuration::parse(&s).map_err(de::Error::custom)
+    }
+}
+
+impl Serialize for RawDuration {
+    fn serialize<S>(&self, s: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match RawDuration::parse(&self.original) {
+            Ok(raw) if raw.value == self.value => s.serialize_str(&self.original),
+            _ => serialize_human(&self.value, s),
+        }
+    }
+}
+
+/// A timeout field that doubles as an on/off switch: `false` disables it, a duration enables it.
+///
+/// `true` is rejected, since it says "on" without saying for how long. Serializes as `false` or
+/// the human form.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum BoolOrDuration {
+    #[default]
+    Disabled,
+    Enabled(Duration),
+}
+
+impl BoolOrDuration {
+    /// The duration when enabled.
+    pub fn duration(&self) -> Option<Duration> {
+        match *self {
+            BoolOrDuration::Disabled => None,
+            BoolOrDuration::Enabled(d) => Some(d),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for BoolOrDuration {
+    fn deserialize<D>(d: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct SwitchVisitor;
+        impl Visitor<'_> for SwitchVisitor {
+            type Value = BoolOrDuration;
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("false or a duration")
+            }
+            fn visit_bool<E: de::Error>(self, v: bool) -> Result<BoolOrDuration, E> {
+                if v {
+                    return Err(E::custom("`true` needs a duration; give one to enable, or `false` to disable"));
+                }
+                Ok(BoolOrDuration::Disabled)
+            }
+            fn visit_u64<E: de::Error>(self, v: u64) -> Result<BoolOrDuration, E> {
+                DurVisitor::default().visit_u64(v).map(BoolOrDuration::Enabled)
+            }
+            fn visit_i64<E: de::Error>(self, v: i64) -> Result<BoolOrDuration, E> {
+                DurVisitor::default().visit_i64(v).map(BoolOrDuration::Enabled)
+            }
+            fn visit_f64<E: de::Error>(self, v: f64) -> Result<BoolOrDuration, E> {
+                DurVisitor::default().visit_f64(v).map(BoolOrDuration::Enabled)
+            }
+            fn visit_str<E: de::Error>(self, v: &str) -> Result<BoolOrDuration, E> {
+                DurVisitor::default().visit_str(v).map(BoolOrDuration::Enabled)
+            }
+        }
+        d.deserialize_any(SwitchVisitor)
+    }
+}
+
+impl Serialize for BoolOrDuration {
+    fn serialize<S>(&self, s: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            BoolOrDuration::Disabled => s.serialize_bool(false),
+            BoolOrDuration::Enabled(d) => serialize_human(d, s),
+        }
+    }
+}
+
+/// A setting where "unset" and "explicitly zero" mean different things, which `Option<Duration>`
+/// blurs once zero is also a meaningful value.
+///
+/// `null` is [`Unset`](Tristate::Unset), any input that reads as zero (`0`, `"0s"`, `"0ms"`) is
+/// [`Zero`](Tristate::Zero), and everything else is [`Value`](Tristate::Value); a zero is never
+/// stored as `Value`. Add `#[serde(default)]` to treat a missing field as `Unset`. Serializes as
+/// `null`, `"0s"` or the human form.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum Tristate {
+    #[default]
+    Unset,
+    Zero,
+    Value(Duration),
+}
+
+impl Tristate {
+    /// The duration unless unset; `Zero` is `Duration::ZERO`.
+    pub fn duration(&self) -> Option<Duration> {
+        match *self {
+            Tristate::Unset => None,
+            Tristate::Zero => Some(Duration::ZERO),
+            Tristate::Value(d) => Some(d),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Tristate {
+    fn deserialize<D>(d: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Ok(match Option::<ExtDuration>::deserialize(d)? {
+            None => Tristate::Unset,
+            Some(ExtDuration(Duration::ZERO)) => Tristate::Zero,
+            Some(ExtDuration(v)) => Tristate::Value(v),
+        })
+    }
+}
+
+impl Serialize for Tristate {
+    fn serialize<S>(&self, s: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            Tristate::Unset => s.serialize_none(),
+            Tristate::Zero => serialize_human(&Duration::ZERO, s),
+            Tristate::Value(d) => serialize_human(d, s),
+        }
+    }
+}
+
 pub mod opt {
     use super::*;
 
@@ -422,4 +4411,72 @@ pub mod opt {
             super::deserialize(d)
         }
     }
+
+    /// `Option` form of the top-level `with` module of the same name: `None` is `null` both ways,
+    /// `Some` goes through that module's own `serialize` / `deserialize`.
+    macro_rules! option_of {
+        ($(#[$doc:meta])* $name:ident) => {
+            $(#[$doc])*
+            pub mod $name {
+                use super::*;
+
+                struct De(Duration);
+                impl<'de> Deserialize<'de> for De {
+                    fn deserialize<D>(d: D) -> Result<Self, D::Error>
+                    where
+                        D: Deserializer<'de>,
+                    {
+                        super::super::$name::deserialize(d).map(De)
+                    }
+                }
+
+                pub fn serialize<S>(v: &Option<Duration>, s: S) -> Result<S::Ok, S::Error>
+                where
+                    S: Serializer,
+                {
+                    match v {
+                        Some(d) => super::super::$name::serialize(d, s),
+                        None => s.serialize_none(),
+                    }
+                }
+                pub fn deserialize<'de, D>(d: D) -> Result<Option<Duration>, D::Error>
+                where
+                    D: Deserializer<'de>,
+                {
+                    let inner = Option::<De>::deserialize(d)?;
+                    Ok(inner.map(|De(d)| d))
+                }
+            }
+        };
+    }
+
+    option_of!(
+        /// Nanoseconds (u64); bare integers are nanoseconds on input
+        nanos_in
+    );
+
+    option_of!(
+        /// Centiseconds (u64); bare integers are centiseconds on input
+        centis
+    );
+
+    option_of!(
+        /// ISO 8601 (`"PT1H30M"`)
+        iso8601
+    );
+
+    option_of!(
+        /// Human output; `None` is allowed but `Some` of a zero duration is rejected
+        positive
+    );
+
+    option_of!(
+        /// Human output; `"now"` and `"immediately"` read as zero
+        relative
+    );
+
+    option_of!(
+        /// Unit-first tokens (`"h1 m30"`) on both sides
+        unit_first
+    );
 }
@@ -0,0 +1,35 @@
+use serde::Deserialize;
+use serde_ext_duration::arith::parse;
+use std::time::Duration;
+
+#[derive(Deserialize)]
+struct Start {
+    #[serde(with = "serde_ext_duration::arith")]
+    at: Duration,
+}
+
+#[test]
+fn adds_and_subtracts_terms() {
+    assert_eq!(parse("1h - 30m").unwrap(), Duration::from_secs(30 * 60));
+    assert_eq!(parse("2h - 15m - 15m").unwrap(), Duration::from_secs(90 * 60));
+    assert_eq!(parse("1h+90s").unwrap(), Duration::from_secs(3690));
+    assert_eq!(parse("1h 30m").unwrap(), Duration::from_secs(5400));
+    assert_eq!(parse("30m - 1h + 1h").unwrap(), Duration::from_secs(30 * 60));
+    assert_eq!(parse("1h - 1h").unwrap(), Duration::ZERO);
+}
+
+#[test]
+fn underflow_and_empty_terms_are_errors() {
+    assert_eq!(parse("30m - 1h").unwrap_err(), "duration underflow");
+    assert_eq!(parse("1h -").unwrap_err(), "in term '': empty duration string");
+    assert!(parse("-30m").is_err());
+    assert!(parse("1h - 5x").unwrap_err().contains("in term '5x'"));
+}
+
+#[test]
+fn deserializes_expressions_and_numbers() {
+    let v: Start = serde_json::from_str(r#"{"at":"1h - 30m"}"#).unwrap();
+    assert_eq!(v.at, Duration::from_secs(1800));
+    let v: Start = serde_json::from_str(r#"{"at":90}"#).unwrap();
+    assert_eq!(v.at, Duration::from_secs(90));
+}
@@ -0,0 +1,54 @@
+use serde::{Deserialize, Serialize};
+use serde_ext_duration::auto_scaled::scale_of;
+use serde_ext_duration::Unit;
+use std::time::Duration;
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct Point {
+    #[serde(with = "serde_ext_duration::auto_scaled")]
+    t: Duration,
+}
+
+fn read(json: &str) -> Result<Duration, String> {
+    serde_json::from_str::<Point>(&format!(r#"{{"t":{json}}}"#)).map(|p| p.t).map_err(|e| e.to_string())
+}
+
+#[test]
+fn picks_the_largest_unit_at_or_above_one() {
+    assert_eq!(scale_of(&Duration::from_secs(5400)), (1.5, Unit::Hours));
+    assert_eq!(scale_of(&Duration::from_secs(3600)), (1.0, Unit::Hours));
+    assert_eq!(scale_of(&Duration::from_secs(3599)), (3599.0 / 60.0, Unit::Minutes));
+    assert_eq!(scale_of(&Duration::from_secs(2 * 86_400)), (2.0, Unit::Days));
+    assert_eq!(scale_of(&Duration::from_millis(1500)), (1.5, Unit::Seconds));
+    assert_eq!(scale_of(&Duration::from_micros(250)), (0.25, Unit::Millis));
+    assert_eq!(scale_of(&Duration::ZERO), (0.0, Unit::Seconds));
+}
+
+#[test]
+fn serializes_value_and_long_name() {
+    let s = serde_json::to_string(&Point { t: Duration::from_secs(5400) }).unwrap();
+    assert_eq!(s, r#"{"t":{"value":1.5,"unit":"hours"}}"#);
+    let s = serde_json::to_string(&Point { t: Duration::from_millis(250) }).unwrap();
+    assert_eq!(s, r#"{"t":{"value":250.0,"unit":"milliseconds"}}"#);
+    for t in [Duration::from_secs(5400), Duration::from_millis(1500), Duration::from_secs(3 * 86_400)] {
+        let s = serde_json::to_string(&Point { t }).unwrap();
+        assert_eq!(serde_json::from_str::<Point>(&s).unwrap().t, t);
+    }
+}
+
+#[test]
+fn deserializes_by_multiplying() {
+    assert_eq!(read(r#"{"unit":"min","value":5}"#).unwrap(), Duration::from_secs(300));
+    assert_eq!(read(r#"{"value":0.5,"unit":"Days"}"#).unwrap(), Duration::from_secs(43_200));
+    assert_eq!(read(r#"{"value":1,"unit":"ms"}"#).unwrap(), Duration::from_millis(1));
+}
+
+#[test]
+fn malformed_objects_are_rejected() {
+    assert!(read(r#"{"value":1}"#).unwrap_err().contains("missing field `unit`"));
+    assert!(read(r#"{"unit":"hours"}"#).unwrap_err().contains("missing field `value`"));
+    assert!(read(r#"{"value":1,"unit":"parsecs"}"#).unwrap_err().contains("unknown unit 'parsecs'"));
+    assert!(read(r#"{"value":-1,"unit":"hours"}"#).unwrap_err().contains("non-negative"));
+    assert!(read(r#"{"value":1,"unit":"h","unit":"m"}"#).unwrap_err().contains("duplicate field `unit`"));
+    assert!(read(r#"{"value":1,"unit":"h","label":"x"}"#).unwrap_err().contains("unknown field `label`"));
+}
@@ -0,0 +1,47 @@
+use serde::{Deserialize, Serialize};
+use serde_ext_duration::base64_nanos::{parse, to_base64_string};
+use std::time::Duration;
+
+#[derive(Serialize, Deserialize, Debug)]
+struct Msg {
+    #[serde(with = "serde_ext_duration::base64_nanos")]
+    t: Duration,
+}
+
+#[test]
+fn encodes_le_nanos() {
+    assert_eq!(to_base64_string(&Duration::ZERO).unwrap(), "AAAAAAAAAAA=");
+    assert_eq!(to_base64_string(&Duration::from_nanos(1)).unwrap(), "AQAAAAAAAAA=");
+    assert_eq!(to_base64_string(&Duration::from_secs(1)).unwrap(), "AMqaOwAAAAA=");
+    assert_eq!(to_base64_string(&Duration::from_secs(3600)).unwrap(), "AKC4MEYDAAA=");
+    assert_eq!(to_base64_string(&Duration::from_nanos(u64::MAX)).unwrap(), "//////////8=");
+    assert!(to_base64_string(&Duration::MAX).is_err());
+}
+
+#[test]
+fn round_trips_across_magnitudes() {
+    for nanos in [0, 1, 999, 1_000_000, 1_500_000_000, 86_400_000_000_000, u64::MAX / 3, u64::MAX] {
+        let m = Msg { t: Duration::from_nanos(nanos) };
+        let json = serde_json::to_string(&m).unwrap();
+        let back: Msg = serde_json::from_str(&json).unwrap();
+        assert_eq!(back.t, m.t, "{json}");
+    }
+}
+
+#[test]
+fn padding_is_optional_on_input() {
+    assert_eq!(parse("AMqaOwAAAAA=").unwrap(), Duration::from_secs(1));
+    assert_eq!(parse("AMqaOwAAAAA").unwrap(), Duration::from_secs(1));
+}
+
+#[test]
+fn rejects_wrong_length_and_bad_input() {
+    // 4 bytes
+    assert_eq!(parse("AMqaOw==").unwrap_err(), "expected 8 bytes of nanoseconds, got 4");
+    // 9 bytes
+    assert_eq!(parse("AMqaOwAAAAAA").unwrap_err(), "expected 8 bytes of nanoseconds, got 9");
+    assert_eq!(parse("AMqa*wAAAAA=").unwrap_err(), "invalid base64 character '*'");
+    assert!(parse("AMqaOwAAAAA===").is_err());
+    assert!(parse("AMqaOwAAAAB=").is_err());
+    assert!(serde_json::from_str::<Msg>(r#"{"t":1}"#).is_err());
+}
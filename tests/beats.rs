@@ -0,0 +1,68 @@
+use serde::{Deserialize, Serialize};
+use serde_ext_duration::beats::{from_beats, to_beats};
+use std::time::Duration;
+
+mod bpm120 {
+    use serde::{Deserializer, Serializer};
+    use std::time::Duration;
+    pub fn serialize<S: Serializer>(d: &Duration, s: S) -> Result<S::Ok, S::Error> {
+        serde_ext_duration::beats::serialize(d, 120.0, s)
+    }
+    pub fn deserialize<'de, D: Deserializer<'de>>(d: D) -> Result<Duration, D::Error> {
+        serde_ext_duration::beats::deserialize(d, 120.0)
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct Note {
+    #[serde(with = "bpm120")]
+    len: Duration,
+}
+
+#[test]
+fn common_tempos() {
+    assert_eq!(from_beats(4.0, 120.0), Duration::from_secs(2));
+    assert_eq!(from_beats(1.0, 60.0), Duration::from_secs(1));
+    assert_eq!(from_beats(1.0, 90.0), Duration::from_nanos(666_666_667));
+    assert_eq!(from_beats(3.0, 90.0), Duration::from_secs(2));
+    assert_eq!(from_beats(0.5, 128.0), Duration::from_nanos(234_375_000));
+    assert_eq!(from_beats(0.0, 140.0), Duration::ZERO);
+    assert_eq!(to_beats(&Duration::from_secs(2), 120.0), 4.0);
+    assert_eq!(to_beats(&Duration::from_millis(500), 60.0), 0.5);
+}
+
+#[test]
+#[should_panic(expected = "bpm must be finite and positive")]
+fn zero_bpm_panics() {
+    from_beats(1.0, 0.0);
+}
+
+#[test]
+#[should_panic(expected = "beats at 120 bpm")]
+fn negative_beats_panic() {
+    from_beats(-1.0, 120.0);
+}
+
+#[test]
+fn serde_roundtrip() {
+    let n: Note = serde_json::from_str(r#"{"len":4}"#).unwrap();
+    assert_eq!(n.len, Duration::from_secs(2));
+    assert_eq!(serde_json::to_string(&n).unwrap(), r#"{"len":4}"#);
+
+    let n: Note = serde_json::from_str(r#"{"len":1.5}"#).unwrap();
+    assert_eq!(n.len, Duration::from_millis(750));
+    assert_eq!(serde_json::to_string(&n).unwrap(), r#"{"len":1.5}"#);
+
+    // a third of a beat is rounded to a thousandth on output
+    let n = Note { len: Duration::from_nanos(166_666_667) };
+    assert_eq!(serde_json::to_string(&n).unwrap(), r#"{"len":0.333}"#);
+}
+
+#[test]
+fn bad_beat_counts_are_errors() {
+    let err = serde_json::from_str::<Note>(r#"{"len":-1}"#).unwrap_err().to_string();
+    assert!(err.contains("beat count must be finite and non-negative"), "{err}");
+    let err = serde_json::from_str::<Note>(r#"{"len":1e300}"#).unwrap_err().to_string();
+    assert!(err.contains("duration overflow"), "{err}");
+    assert!(serde_json::from_str::<Note>(r#"{"len":"4"}"#).is_err());
+}
@@ -0,0 +1,37 @@
+use serde::{Deserialize, Serialize};
+use serde_ext_duration::BoolOrDuration;
+use std::time::Duration;
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct Cfg {
+    idle_timeout: BoolOrDuration,
+}
+
+fn parse(v: &str) -> Result<BoolOrDuration, String> {
+    serde_json::from_str::<Cfg>(&format!(r#"{{"idle_timeout":{v}}}"#))
+        .map(|c| c.idle_timeout)
+        .map_err(|e| e.to_string())
+}
+
+#[test]
+fn false_disables_and_durations_enable() {
+    assert_eq!(parse("false").unwrap(), BoolOrDuration::Disabled);
+    assert_eq!(parse(r#""5m""#).unwrap(), BoolOrDuration::Enabled(Duration::from_secs(300)));
+    assert_eq!(parse("30").unwrap().duration(), Some(Duration::from_secs(30)));
+    assert_eq!(parse("1.5").unwrap().duration(), Some(Duration::from_millis(1500)));
+    assert!(parse("true").unwrap_err().contains("`true` needs a duration"));
+    assert!(parse(r#""soon""#).is_err());
+    assert!(parse("-1").is_err());
+}
+
+#[test]
+fn serializes_false_or_human() {
+    let off = Cfg { idle_timeout: BoolOrDuration::Disabled };
+    assert_eq!(serde_json::to_string(&off).unwrap(), r#"{"idle_timeout":false}"#);
+    let on = Cfg { idle_timeout: BoolOrDuration::Enabled(Duration::from_secs(90)) };
+    let j = serde_json::to_string(&on).unwrap();
+    assert_eq!(j, r#"{"idle_timeout":"1m 30s"}"#);
+    assert_eq!(serde_json::from_str::<Cfg>(&j).unwrap(), on);
+    let y: Cfg = serde_yaml::from_str("idle_timeout: false").unwrap();
+    assert_eq!(y, off);
+}
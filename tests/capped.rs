@@ -0,0 +1,71 @@
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+mod capped_30d {
+    use serde::{Deserializer, Serializer};
+    use std::time::Duration;
+    pub const MAX: Duration = Duration::from_secs(30 * 86_400);
+    pub fn serialize<S: Serializer>(d: &Duration, s: S) -> Result<S::Ok, S::Error> {
+        serde_ext_duration::capped::serialize(d, MAX, ">30d", s)
+    }
+    pub fn deserialize<'de, D: Deserializer<'de>>(d: D) -> Result<Duration, D::Error> {
+        serde_ext_duration::capped::deserialize(d, MAX, ">30d")
+    }
+}
+
+mod forever {
+    use serde::{Deserializer, Serializer};
+    use std::time::Duration;
+    pub fn serialize<S: Serializer>(d: &Duration, s: S) -> Result<S::Ok, S::Error> {
+        serde_ext_duration::capped::serialize(d, Duration::from_secs(86_400), "∞", s)
+    }
+    pub fn deserialize<'de, D: Deserializer<'de>>(d: D) -> Result<Duration, D::Error> {
+        serde_ext_duration::capped::deserialize(d, Duration::from_secs(86_400), "∞")
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct Row {
+    #[serde(with = "capped_30d")]
+    ttl: Duration,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct Lease {
+    #[serde(with = "forever")]
+    t: Duration,
+}
+
+#[test]
+fn below_and_at_the_cap_are_exact() {
+    let s = serde_json::to_string(&Row { ttl: Duration::from_secs(90) }).unwrap();
+    assert_eq!(s, r#"{"ttl":"1m 30s"}"#);
+    let s = serde_json::to_string(&Row { ttl: capped_30d::MAX }).unwrap();
+    assert_eq!(s, r#"{"ttl":"30d"}"#);
+}
+
+#[test]
+fn above_the_cap_writes_the_sentinel() {
+    let s = serde_json::to_string(&Row { ttl: Duration::MAX }).unwrap();
+    assert_eq!(s, r#"{"ttl":">30d"}"#);
+    let s = serde_json::to_string(&Lease { t: Duration::from_secs(4_000_000 * 86_400) }).unwrap();
+    assert_eq!(s, r#"{"t":"∞"}"#);
+}
+
+#[test]
+fn sentinel_reads_back_as_the_cap() {
+    let r: Row = serde_json::from_str(r#"{"ttl":">30d"}"#).unwrap();
+    assert_eq!(r.ttl, capped_30d::MAX);
+    let l: Lease = serde_json::from_str(r#"{"t":" ∞ "}"#).unwrap();
+    assert_eq!(l.t, Duration::from_secs(86_400));
+}
+
+#[test]
+fn other_input_is_read_normally() {
+    let r: Row = serde_json::from_str(r#"{"ttl":"2h"}"#).unwrap();
+    assert_eq!(r.ttl, Duration::from_secs(7200));
+    let r: Row = serde_json::from_str(r#"{"ttl":60}"#).unwrap();
+    assert_eq!(r.ttl, Duration::from_secs(60));
+    let err = serde_json::from_str::<Row>(r#"{"ttl":">31d"}"#).unwrap_err().to_string();
+    assert!(err.contains("expected number"), "{err}");
+}
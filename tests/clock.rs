@@ -0,0 +1,46 @@
+use serde::{Deserialize, Serialize};
+use serde_ext_duration::clock::{self, parse, to_clock_string};
+use std::time::Duration;
+
+#[derive(Serialize, Deserialize, Debug)]
+struct Row {
+    #[serde(with = "serde_ext_duration::clock")]
+    t: Duration,
+}
+
+#[test]
+fn arity_picks_the_layout() {
+    assert_eq!(parse("5:00").unwrap(), Duration::from_secs(300));
+    assert_eq!(parse("0:05:00").unwrap(), Duration::from_secs(300));
+    assert_eq!(parse("1:05:00").unwrap(), Duration::from_secs(3900));
+    assert_eq!(parse("90:00").unwrap(), Duration::from_secs(5400));
+    assert_eq!(parse("0:01.5").unwrap(), Duration::from_millis(1500));
+}
+
+#[test]
+fn rejects_bad_fields_and_four_parts() {
+    assert_eq!(parse("1:02:00:00").unwrap_err(), "expected MM:SS or HH:MM:SS");
+    assert_eq!(parse("5").unwrap_err(), "expected MM:SS or HH:MM:SS");
+    assert_eq!(parse("1:60").unwrap_err(), "clock field '60' must be below 60");
+    assert!(parse("1::00").is_err());
+    assert!(parse("1:5x").is_err());
+    assert!(parse("1.5:00").is_err());
+}
+
+#[test]
+fn days_extension() {
+    assert_eq!(clock::days::parse("1:02:00:00").unwrap(), Duration::from_secs(26 * 3600));
+    assert_eq!(clock::days::parse("5:00").unwrap(), Duration::from_secs(300));
+    assert!(clock::days::parse("1:24:00:00").unwrap_err().contains("below 24"));
+    assert!(clock::days::parse("1:1:1:1:1").is_err());
+}
+
+#[test]
+fn serializes_h_mm_ss() {
+    assert_eq!(to_clock_string(&Duration::from_secs(300)), "0:05:00");
+    assert_eq!(to_clock_string(&Duration::from_secs(26 * 3600 + 61)), "26:01:01");
+    assert_eq!(to_clock_string(&Duration::from_millis(1250)), "0:00:01.250");
+    let j = serde_json::to_string(&Row { t: Duration::from_secs(3900) }).unwrap();
+    assert_eq!(j, r#"{"t":"1:05:00"}"#);
+    assert_eq!(serde_json::from_str::<Row>(&j).unwrap().t, Duration::from_secs(3900));
+}
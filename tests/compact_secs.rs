@@ -0,0 +1,32 @@
+use serde::{Deserialize, Serialize};
+use serde_ext_duration::compact_secs::{parse, to_base36};
+use std::time::Duration;
+
+#[derive(Serialize, Deserialize, Debug)]
+struct Link {
+    #[serde(with = "serde_ext_duration::compact_secs")]
+    t: Duration,
+}
+
+#[test]
+fn round_trips_across_magnitudes() {
+    for secs in [0, 1, 35, 36, 100, 3600, 86_400 * 365, u32::MAX as u64, u64::MAX] {
+        let d = Duration::from_secs(secs);
+        assert_eq!(parse(&to_base36(&d)).unwrap(), d, "{secs}");
+        let j = serde_json::to_string(&Link { t: d }).unwrap();
+        assert_eq!(serde_json::from_str::<Link>(&j).unwrap().t, d);
+    }
+    assert_eq!(to_base36(&Duration::from_secs(3600)), "2s0");
+    assert_eq!(to_base36(&Duration::from_millis(35_999)), "z");
+    assert_eq!(to_base36(&Duration::MAX), "3w5e11264sgsf");
+    assert_eq!(parse("2S0").unwrap(), Duration::from_secs(3600));
+}
+
+#[test]
+fn rejects_non_base36_input() {
+    assert!(parse("").is_err());
+    assert!(parse("+1").is_err());
+    assert!(parse("1 h").is_err());
+    assert_eq!(parse("3w5e11264sgsg").unwrap_err(), "duration overflow");
+    assert!(serde_json::from_str::<Link>(r#"{"t":100}"#).is_err());
+}
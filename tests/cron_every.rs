@@ -0,0 +1,41 @@
+use serde::{Deserialize, Serialize};
+use serde_ext_duration::cron_every::{parse, to_cron_string};
+use std::time::Duration;
+
+#[derive(Serialize, Deserialize, Debug)]
+struct Job {
+    #[serde(with = "serde_ext_duration::cron_every")]
+    schedule: Duration,
+}
+
+#[test]
+fn writes_go_style_intervals() {
+    assert_eq!(to_cron_string(&Duration::from_secs(90 * 60)), "@every 1h30m");
+    assert_eq!(to_cron_string(&Duration::from_secs(2 * 86_400)), "@every 48h");
+    assert_eq!(to_cron_string(&Duration::from_millis(1_500)), "@every 1s500ms");
+    assert_eq!(to_cron_string(&Duration::ZERO), "@every 0s");
+}
+
+#[test]
+fn requires_the_prefix() {
+    assert_eq!(parse("@every 1h30m").unwrap(), Duration::from_secs(90 * 60));
+    assert_eq!(parse("  @every\t1h 30m ").unwrap(), Duration::from_secs(90 * 60));
+    assert_eq!(parse("@every 1d").unwrap(), Duration::from_secs(86_400));
+    assert_eq!(parse("1h30m").unwrap_err(), "expected '@every <duration>', got '1h30m'");
+    assert!(parse("@every1h").is_err());
+    assert!(parse("@hourly").is_err());
+    assert!(parse("@every").is_err());
+    assert!(serde_json::from_str::<Job>(r#"{"schedule":3600}"#).is_err());
+}
+
+#[test]
+fn round_trips() {
+    for secs in [1, 59, 60, 3_661, 86_400, 7 * 86_400 + 5] {
+        let job = Job { schedule: Duration::from_secs(secs) };
+        let json = serde_json::to_string(&job).unwrap();
+        let back: Job = serde_json::from_str(&json).unwrap();
+        assert_eq!(back.schedule, job.schedule, "{json}");
+    }
+    let back: Job = serde_json::from_str(r#"{"schedule":"@every 250ms"}"#).unwrap();
+    assert_eq!(back.schedule, Duration::from_millis(250));
+}
@@ -0,0 +1,51 @@
+#![cfg(feature = "rust_decimal")]
+
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use serde_ext_duration::decimal_secs::{from_decimal, to_decimal};
+use std::str::FromStr;
+use std::time::Duration;
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct Bill {
+    #[serde(with = "serde_ext_duration::decimal_secs")]
+    used: Duration,
+}
+
+fn dec(s: &str) -> Decimal {
+    Decimal::from_str(s).unwrap()
+}
+
+#[test]
+fn exact_to_the_nanosecond() {
+    assert_eq!(to_decimal(&Duration::from_millis(1500)), dec("1.5"));
+    assert_eq!(to_decimal(&Duration::new(1, 1)), dec("1.000000001"));
+    assert_eq!(to_decimal(&Duration::ZERO), Decimal::ZERO);
+    assert_eq!(to_decimal(&Duration::MAX), dec("18446744073709551615.999999999"));
+    for d in [Duration::new(1, 1), Duration::new(86_400 * 365, 123_456_789), Duration::MAX] {
+        assert_eq!(from_decimal(to_decimal(&d)).unwrap(), d);
+    }
+    // what f64 cannot hold: one nanosecond on top of a large value
+    let d = Duration::new(10_000_000, 1);
+    assert_ne!(Duration::from_secs_f64(d.as_secs_f64()), d);
+    assert_eq!(from_decimal(to_decimal(&d)).unwrap(), d);
+}
+
+#[test]
+fn serde_roundtrip() {
+    let b = Bill { used: Duration::new(3600, 500_000_001) };
+    let s = serde_json::to_string(&b).unwrap();
+    assert_eq!(s, r#"{"used":"3600.500000001"}"#);
+    assert_eq!(serde_json::from_str::<Bill>(&s).unwrap(), b);
+    let v: Bill = serde_json::from_str(r#"{"used":90}"#).unwrap();
+    assert_eq!(v.used, Duration::from_secs(90));
+}
+
+#[test]
+fn rejects_negative_and_rounds_extra_digits() {
+    assert_eq!(from_decimal(dec("-1")).unwrap_err(), "duration cannot be negative");
+    assert_eq!(from_decimal(dec("-0")).unwrap(), Duration::ZERO);
+    assert_eq!(from_decimal(dec("0.0000000015")).unwrap(), Duration::from_nanos(2));
+    assert_eq!(from_decimal(dec("18446744073709551616")).unwrap_err(), "duration too large");
+    assert!(serde_json::from_str::<Bill>(r#"{"used":"-5"}"#).is_err());
+}
@@ -0,0 +1,61 @@
+use serde::{Deserialize, Serialize};
+use serde_ext_duration_derive::durations;
+use std::time::Duration;
+
+#[durations]
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct Cfg {
+    #[duration]
+    human: Duration,
+    #[duration(format = "millis")]
+    millis: Duration,
+    #[duration(format = "nanos")]
+    nanos: Duration,
+    #[duration(format = "secs", optional)]
+    secs: Option<Duration>,
+    #[serde(rename = "f")]
+    #[duration(format = "secs_f64_ms")]
+    float: Duration,
+    #[duration(format = "iso8601", optional)]
+    iso: Option<Duration>,
+}
+
+#[test]
+fn maps_formats_to_modules() {
+    let v = Cfg {
+        human: Duration::from_secs(90),
+        millis: Duration::from_millis(1500),
+        nanos: Duration::from_nanos(7),
+        secs: Some(Duration::from_secs(3)),
+        float: Duration::from_millis(250),
+        iso: Some(Duration::from_secs(5400)),
+    };
+    let s = serde_json::to_string(&v).unwrap();
+    assert_eq!(s, r#"{"human":"1m 30s","millis":1500,"nanos":7,"secs":3,"f":0.25,"iso":"PT1H30M"}"#);
+    let back: Cfg = serde_json::from_str(&s).unwrap();
+    assert_eq!((back.human, back.nanos, back.secs, back.float), (v.human, v.nanos, v.secs, v.float));
+    assert_eq!(back.iso, v.iso);
+}
+
+#[test]
+fn optional_fields_default_to_none() {
+    let v: Cfg = serde_json::from_str(r#"{"human":"1s","millis":1,"nanos":1,"f":1}"#).unwrap();
+    assert_eq!((v.secs, v.iso), (None, None));
+}
+
+#[durations]
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+enum Event {
+    Wait(#[duration(format = "secs")] Duration),
+    Timeout {
+        #[duration]
+        after: Duration,
+    },
+}
+
+#[test]
+fn applies_to_enum_variant_fields() {
+    assert_eq!(serde_json::to_string(&Event::Wait(Duration::from_secs(5))).unwrap(), r#"{"Wait":5}"#);
+    let e: Event = serde_json::from_str(r#"{"Timeout":{"after":"2m"}}"#).unwrap();
+    assert_eq!(e, Event::Timeout { after: Duration::from_secs(120) });
+}
@@ -55,6 +55,27 @@ fn error_negative_int() {
     assert!(err.to_string().to_lowercase().contains("negative"));
 }
 
+#[test]
+fn error_most_negative_ints() {
+    use serde::de::{value::Error, IntoDeserializer};
+    let err = serde_json::from_str::<Root>(r#"{ "t": -9223372036854775808 }"#).unwrap_err();
+    assert!(err.to_string().contains("negative duration not allowed"), "{err}");
+
+    let min = || IntoDeserializer::<Error>::into_deserializer(i64::MIN);
+    for res in [
+        serde_ext_duration::deserialize(min()),
+        serde_ext_duration::nanos_in::deserialize(min()),
+        serde_ext_duration::centis::deserialize(min()),
+        serde_ext_duration::heuristic::deserialize(min()),
+        serde_ext_duration::millis_padded::deserialize(min()),
+    ] {
+        assert_eq!(res.unwrap_err().to_string(), "negative duration not allowed");
+    }
+    // no visitor takes `i128`, so its minimum is a plain type error
+    let min_128 = IntoDeserializer::<Error>::into_deserializer(i128::MIN);
+    assert!(serde_ext_duration::deserialize(min_128).is_err());
+}
+
 #[test]
 fn error_negative_float() {
     let err = serde_json::from_str::<Root>(r#"{ "t": -0.1 }"#).unwrap_err();
@@ -72,3 +93,218 @@ fn error_unknown_unit() {
     let err = serde_json::from_str::<Root>(r#"{ "t": "5q" }"#).unwrap_err();
     assert!(err.to_string().contains("unknown unit"));
 }
+
+#[test]
+fn string_beyond_u64_millis_up_to_duration_max() {
+    let v: Root = serde_json::from_str(r#"{ "t": "213503982334601d" }"#).unwrap();
+    assert_eq!(v.t, Duration::from_secs(213_503_982_334_601 * 86_400));
+
+    let err = serde_json::from_str::<Root>(r#"{ "t": "213503982334602d" }"#).unwrap_err();
+    assert!(err.to_string().contains("duration too large"));
+}
+
+#[derive(Debug, Deserialize)]
+struct Nanos {
+    #[serde(with = "serde_ext_duration::nanos_in")]
+    t: Duration,
+}
+
+#[test]
+fn nanos_in_ints_are_nanoseconds() {
+    let v: Nanos = serde_json::from_str(r#"{ "t": 1500 }"#).unwrap();
+    assert_eq!(v.t, Duration::from_nanos(1500));
+
+    // floats and strings keep their flexible meaning
+    let v: Nanos = serde_json::from_str(r#"{ "t": 1.5 }"#).unwrap();
+    assert_eq!(v.t, Duration::from_millis(1500));
+    let v: Nanos = serde_json::from_str(r#"{ "t": "2s" }"#).unwrap();
+    assert_eq!(v.t, Duration::from_secs(2));
+
+    let err = serde_json::from_str::<Nanos>(r#"{ "t": -1 }"#).unwrap_err();
+    assert!(err.to_string().contains("negative"));
+}
+
+#[test]
+fn units_table_matches_parser() {
+    for &(unit, nanos) in serde_ext_duration::UNITS {
+        let parsed = serde_ext_duration::parse_str(&format!("2{unit}")).unwrap();
+        assert_eq!(parsed.as_nanos(), 2 * nanos, "unit {unit}");
+    }
+    assert!(serde_ext_duration::UNITS.iter().any(|&(u, _)| u == "ms"));
+
+    // `units()` is the full table, both ways: every entry parses, and every spelling the parser
+    // takes is listed
+    let all: Vec<(&str, u128)> = serde_ext_duration::units().collect();
+    for &(unit, nanos) in &all {
+        let parsed = serde_ext_duration::parse_str(&format!("2{unit}")).unwrap();
+        assert_eq!(parsed.as_nanos(), 2 * nanos, "unit {unit}");
+    }
+    assert!(serde_ext_duration::UNITS.iter().all(|u| all.contains(u)));
+    let candidates = ["d", "h", "m", "s", "ms", "ns", "us", "µs", "w", "wk", "month", "y", "hours", "secs"];
+    let rejected = ["x", "hs", "ds", "sek", "mo", "fortnight", "usecs", "msecs", "ys"];
+    for unit in candidates.iter().chain(&rejected) {
+        let parses = serde_ext_duration::parse_str(&format!("2{unit}")).is_ok();
+        assert_eq!(parses, all.iter().any(|(u, _)| u == unit), "unit {unit}");
+    }
+}
+
+#[test]
+fn whitespace_between_number_and_unit_binds_correctly() {
+    use serde_ext_duration::parse_str;
+    let ninety_min = Duration::from_secs(90 * 60);
+    for s in ["1 h30m", "1h 30 m", "1 h 30 m", "1h30 m", " 1 h\t30m ", "1\nh 30\tm"] {
+        assert_eq!(parse_str(s).unwrap(), ninety_min, "{s:?}");
+    }
+    // "1 ms" is one millisecond token, not minutes followed by a dangling "s"
+    assert_eq!(parse_str("1 ms").unwrap(), Duration::from_millis(1));
+    assert_eq!(parse_str("2 m 5 s").unwrap(), Duration::from_secs(125));
+
+    // a unit never jumps over another number
+    assert!(parse_str("1 30m").unwrap_err().contains("expected unit after number at position 0"));
+    assert!(parse_str("1h 30").unwrap_err().contains("expected unit after number at position 3"));
+    assert!(parse_str("1 m s").unwrap_err().contains("expected number at position 4"));
+}
+
+#[test]
+fn bytes_are_parsed_as_utf8_strings() {
+    use serde::de::value::{BytesDeserializer, Error};
+    let v = serde_ext_duration::deserialize(BytesDeserializer::<Error>::new(b"1m 30s")).unwrap();
+    assert_eq!(v, Duration::from_secs(90));
+
+    let err = serde_ext_duration::deserialize(BytesDeserializer::<Error>::new(b"\xff5s")).unwrap_err();
+    assert!(err.to_string().contains("not valid UTF-8"));
+}
+
+#[derive(Debug, Deserialize)]
+struct HumanOrSecs {
+    #[serde(with = "serde_ext_duration::human_or_secs")]
+    t: Duration,
+}
+
+#[test]
+fn human_or_secs_accepts_strings_and_ints_only() {
+    let v: HumanOrSecs = serde_json::from_str(r#"{ "t": "1m 30s" }"#).unwrap();
+    assert_eq!(v.t, Duration::from_secs(90));
+    let v: HumanOrSecs = serde_json::from_str(r#"{ "t": 90 }"#).unwrap();
+    assert_eq!(v.t, Duration::from_secs(90));
+
+    let err = serde_json::from_str::<HumanOrSecs>(r#"{ "t": 1.5 }"#).unwrap_err();
+    assert!(err.to_string().contains("float durations are not allowed"));
+    let err = serde_json::from_str::<HumanOrSecs>(r#"{ "t": -3 }"#).unwrap_err();
+    assert!(err.to_string().contains("negative"));
+}
+
+#[derive(Debug, Deserialize)]
+struct Retries {
+    #[serde(with = "serde_ext_duration::non_empty_vec")]
+    backoff: Vec<Duration>,
+}
+
+#[test]
+fn non_empty_vec_parses_and_rejects_empty() {
+    let v: Retries = serde_json::from_str(r#"{ "backoff": ["1s", 2, 0.5] }"#).unwrap();
+    assert_eq!(v.backoff, vec![Duration::from_secs(1), Duration::from_secs(2), Duration::from_millis(500)]);
+
+    let err = serde_json::from_str::<Retries>(r#"{ "backoff": [] }"#).unwrap_err();
+    assert!(err.to_string().contains("expected at least one duration"));
+    let err = serde_json::from_str::<Retries>(r#"{ "backoff": ["1s", "5q"] }"#).unwrap_err();
+    assert!(err.to_string().contains("unknown unit"));
+}
+
+#[test]
+fn quoted_numbers_match_unquoted() {
+    let v: Root = serde_yaml::from_str("t: \"90\"").unwrap();
+    assert_eq!(v.t, Duration::from_secs(90));
+    let v: Root = serde_yaml::from_str("t: '1.5'").unwrap();
+    assert_eq!(v.t, Duration::from_millis(1500));
+    let v: Root = serde_yaml::from_str("t: \"1h\"").unwrap();
+    assert_eq!(v.t, Duration::from_secs(3600));
+    let v: Root = serde_json::from_str(r#"{ "t": " 42 " }"#).unwrap();
+    assert_eq!(v.t, Duration::from_secs(42));
+
+    // same gates as the numeric path
+    let v: Nanos = serde_json::from_str(r#"{ "t": "1500" }"#).unwrap();
+    assert_eq!(v.t, Duration::from_nanos(1500));
+    let err = serde_json::from_str::<HumanOrSecs>(r#"{ "t": "1.5" }"#).unwrap_err();
+    assert!(err.to_string().contains("float durations are not allowed"));
+    let err = serde_json::from_str::<Root>(r#"{ "t": "-1" }"#).unwrap_err();
+    assert!(err.to_string().contains("expected number"));
+}
+
+#[test]
+fn float_carry_for_large_values() {
+    let v: Root = serde_json::from_str(r#"{ "t": 9999999.9996 }"#).unwrap();
+    assert_eq!(v.t, Duration::from_secs(10_000_000));
+    let v: Root = serde_json::from_str(r#"{ "t": 123456789.9999 }"#).unwrap();
+    assert_eq!(v.t, Duration::from_secs(123_456_790));
+    let v: Root = serde_json::from_str(r#"{ "t": 9999999.9994 }"#).unwrap();
+    assert_eq!(v.t, Duration::new(9_999_999, 999_000_000));
+
+    // 2^52 - 0.5 is exactly representable: half a second, no carry (fed directly, as serde_json's
+    // default float parsing is not correctly rounded this far out)
+    use serde::de::{value::Error, IntoDeserializer};
+    let v = serde_ext_duration::deserialize(IntoDeserializer::<Error>::into_deserializer(4503599627370495.5f64));
+    assert_eq!(v.unwrap(), Duration::new(4_503_599_627_370_495, 500_000_000));
+    // beyond 2^53 floats are whole numbers, so the fractional path contributes nothing
+    let v = serde_ext_duration::deserialize(IntoDeserializer::<Error>::into_deserializer(2f64.powi(60)));
+    assert_eq!(v.unwrap(), Duration::from_secs(1 << 60));
+}
+
+#[test]
+fn single_char_input_has_clear_error() {
+    use serde::de::{value::Error, IntoDeserializer};
+    let err = serde_ext_duration::deserialize(IntoDeserializer::<Error>::into_deserializer('5')).unwrap_err();
+    assert_eq!(err.to_string(), "duration must be a string or number, not a single char");
+}
+
+#[test]
+fn huge_floats_overflow_before_casting() {
+    use serde::de::{value::Error, IntoDeserializer};
+    for v in [1e30, 18446744073709551616.0, f64::MAX] {
+        let err = serde_ext_duration::deserialize(IntoDeserializer::<Error>::into_deserializer(v)).unwrap_err();
+        assert_eq!(err.to_string(), "duration overflow", "{v}");
+    }
+    let err = serde_json::from_str::<Root>(r#"{ "t": 1e30 }"#).unwrap_err();
+    assert!(err.to_string().contains("duration overflow"));
+}
+
+#[test]
+fn units_are_case_insensitive() {
+    use serde_ext_duration::parse_str;
+    for s in ["250ms", "250Ms", "250MS", "250mS"] {
+        assert_eq!(parse_str(s).unwrap(), Duration::from_millis(250), "{s}");
+    }
+    assert_eq!(parse_str("5S").unwrap(), Duration::from_secs(5));
+    assert_eq!(parse_str("1H").unwrap(), Duration::from_secs(3600));
+    assert_eq!(parse_str("2D").unwrap(), Duration::from_secs(2 * 86_400));
+    assert_eq!(parse_str("3M").unwrap(), Duration::from_secs(180));
+    assert_eq!(parse_str("1H 30M 5S 250MS").unwrap(), Duration::from_millis(5_405_250));
+    assert_eq!(parse_str("2 Hours").unwrap(), Duration::from_secs(7200));
+    assert_eq!(parse_str("10 MIN").unwrap(), Duration::from_secs(600));
+}
+
+#[test]
+fn std_debug_output_parses_back_exactly() {
+    use serde_ext_duration::parse_str;
+    let cases = [
+        Duration::ZERO,
+        Duration::from_nanos(1),
+        Duration::from_nanos(999),
+        Duration::from_nanos(1_500),
+        Duration::from_micros(1),
+        Duration::from_micros(250),
+        Duration::from_millis(250),
+        Duration::new(0, 1_234_567),
+        Duration::from_millis(1_500),
+        Duration::new(1, 1),
+        Duration::from_secs(3600),
+        Duration::new(93_784, 250_000_001),
+        Duration::MAX,
+    ];
+    for d in cases {
+        let debug = format!("{d:?}");
+        assert_eq!(parse_str(&debug).unwrap(), d, "{debug}");
+        let v: Root = serde_json::from_value(serde_json::json!({ "t": debug })).unwrap();
+        assert_eq!(v.t, d, "{debug}");
+    }
+}
@@ -0,0 +1,38 @@
+use serde::Deserialize;
+use serde_ext_duration::with_default_on_empty;
+use std::time::Duration;
+
+mod or_30s {
+    use serde::Deserializer;
+    use std::time::Duration;
+    pub fn deserialize<'de, D: Deserializer<'de>>(d: D) -> Result<Duration, D::Error> {
+        serde_ext_duration::empty_default::deserialize(d, Duration::from_secs(30))
+    }
+}
+
+#[derive(Deserialize)]
+struct Form {
+    #[serde(deserialize_with = "or_30s::deserialize")]
+    timeout: Duration,
+}
+
+#[test]
+fn parser_maps_blank_to_default() {
+    let parse = with_default_on_empty(Duration::from_secs(30));
+    assert_eq!(parse("").unwrap(), Duration::from_secs(30));
+    assert_eq!(parse("  \t").unwrap(), Duration::from_secs(30));
+    assert_eq!(parse("5m").unwrap(), Duration::from_secs(300));
+    assert!(parse("5x").is_err());
+}
+
+#[test]
+fn empty_form_field_uses_default() {
+    let f: Form = serde_json::from_str(r#"{"timeout":""}"#).unwrap();
+    assert_eq!(f.timeout, Duration::from_secs(30));
+    let f: Form = serde_json::from_str(r#"{"timeout":"1m"}"#).unwrap();
+    assert_eq!(f.timeout, Duration::from_secs(60));
+    let f: Form = serde_json::from_str(r#"{"timeout":"90"}"#).unwrap();
+    assert_eq!(f.timeout, Duration::from_secs(90));
+    let f: Form = serde_json::from_str(r#"{"timeout":2}"#).unwrap();
+    assert_eq!(f.timeout, Duration::from_secs(2));
+}
@@ -0,0 +1,31 @@
+use serde::Deserialize;
+use serde_ext_duration::env::with_default;
+use std::time::Duration;
+
+#[derive(Deserialize)]
+struct Cfg {
+    #[serde(with = "serde_ext_duration::env")]
+    t: Duration,
+}
+
+#[test]
+fn plain_string_parses_as_usual() {
+    assert_eq!(with_default("1m 30s").unwrap(), Duration::from_secs(90));
+    let v: Cfg = serde_json::from_str(r#"{ "t": "250ms" }"#).unwrap();
+    assert_eq!(v.t, Duration::from_millis(250));
+}
+
+#[test]
+fn env_default_form_uses_fallback() {
+    assert_eq!(with_default("${TIMEOUT:-30s}").unwrap(), Duration::from_secs(30));
+    assert_eq!(with_default("  ${T:-1h 5m} ").unwrap(), Duration::from_secs(3900));
+    let v: Cfg = serde_yaml::from_str("t: '${TIMEOUT:-2m}'").unwrap();
+    assert_eq!(v.t, Duration::from_secs(120));
+}
+
+#[test]
+fn env_form_errors() {
+    assert!(with_default("${TIMEOUT}").unwrap_err().contains("VAR:-DEFAULT"));
+    assert!(with_default("${TIMEOUT:-5q}").unwrap_err().contains("unknown unit"));
+    assert!(with_default("${TIMEOUT:-}").unwrap_err().contains("empty duration"));
+}
@@ -0,0 +1,27 @@
+use serde::{Deserialize, Serialize};
+use std::time::{Duration, UNIX_EPOCH};
+
+#[derive(Serialize, Deserialize, Debug)]
+struct Doc {
+    #[serde(with = "serde_ext_duration::firebase")]
+    created: Duration,
+}
+
+#[test]
+fn reads_and_writes_underscore_keys() {
+    let doc: Doc = serde_json::from_str(r#"{"created":{"_seconds":1700000000,"_nanoseconds":500}}"#).unwrap();
+    assert_eq!(doc.created, Duration::new(1_700_000_000, 500));
+    assert_eq!(UNIX_EPOCH + doc.created, UNIX_EPOCH + Duration::new(1_700_000_000, 500));
+    let j = serde_json::to_string(&doc).unwrap();
+    assert_eq!(j, r#"{"created":{"_seconds":1700000000,"_nanoseconds":500}}"#);
+}
+
+#[test]
+fn rejects_incomplete_or_invalid_timestamps() {
+    let parse = |s: &str| serde_json::from_str::<Doc>(s).unwrap_err().to_string();
+    assert!(parse(r#"{"created":{"_seconds":1}}"#).contains("missing field `_nanoseconds`"));
+    assert!(parse(r#"{"created":{"_seconds":1,"_nanoseconds":1000000000}}"#).contains("below 1000000000"));
+    assert!(parse(r#"{"created":{"_seconds":-1,"_nanoseconds":0}}"#).contains("invalid value"));
+    assert!(parse(r#"{"created":{"seconds":1,"_nanoseconds":0}}"#).contains("unknown field `seconds`"));
+    assert!(parse(r#"{"created":{"_seconds":1,"_seconds":2,"_nanoseconds":0}}"#).contains("duplicate field"));
+}
@@ -0,0 +1,62 @@
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde_ext_duration::frames::{from_frames, to_frames};
+use std::time::Duration;
+
+mod fps24 {
+    use super::*;
+    pub fn serialize<S: Serializer>(d: &Duration, s: S) -> Result<S::Ok, S::Error> {
+        serde_ext_duration::frames::serialize(d, 24.0, s)
+    }
+    pub fn deserialize<'de, D: Deserializer<'de>>(d: D) -> Result<Duration, D::Error> {
+        serde_ext_duration::frames::deserialize(d, 24.0)
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+struct Clip {
+    #[serde(with = "fps24")]
+    length: Duration,
+}
+
+#[test]
+fn frames_convert_both_ways() {
+    assert_eq!(from_frames(48, 24.0), Duration::from_secs(2));
+    assert_eq!(from_frames(1, 30.0), Duration::from_nanos(33_333_333));
+    assert_eq!(to_frames(&Duration::from_secs(10), 29.97), 300);
+    assert_eq!(from_frames(2997, 29.97), Duration::from_secs(100));
+}
+
+#[test]
+fn frames_round_to_nearest_frame() {
+    // 1 frame at 24fps is 41.666ms
+    assert_eq!(to_frames(&Duration::from_millis(20), 24.0), 0);
+    assert_eq!(to_frames(&Duration::from_millis(21), 24.0), 1);
+    assert_eq!(to_frames(&Duration::from_millis(1030), 24.0), 25);
+}
+
+#[test]
+fn frames_serde_round_trip() {
+    let j = serde_json::to_string(&Clip { length: Duration::from_millis(2500) }).unwrap();
+    assert_eq!(j, r#"{"length":60}"#);
+    let back: Clip = serde_json::from_str(&j).unwrap();
+    assert_eq!(back.length, Duration::from_millis(2500));
+    assert!(serde_json::from_str::<Clip>(r#"{"length":"2s"}"#).is_err());
+}
+
+#[test]
+#[should_panic(expected = "fps must be finite and positive")]
+fn frames_reject_zero_fps() {
+    from_frames(1, 0.0);
+}
+
+#[test]
+fn huge_counts_error_instead_of_panicking() {
+    use serde::de::{value::Error, IntoDeserializer};
+    use serde_ext_duration::frames::try_from_frames;
+    assert_eq!(try_from_frames(u64::MAX, 0.5), None);
+    assert_eq!(try_from_frames(48, 24.0), Some(Duration::from_secs(2)));
+
+    let de = IntoDeserializer::<Error>::into_deserializer(u64::MAX);
+    let err = serde_ext_duration::frames::deserialize(de, 0.5).unwrap_err();
+    assert_eq!(err.to_string(), "duration overflow");
+}
@@ -0,0 +1,60 @@
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct Rec {
+    #[serde(with = "serde_ext_duration::heuristic")]
+    t: Duration,
+}
+
+mod ms_from_1000 {
+    use serde::Deserializer;
+    use std::time::Duration;
+    pub fn deserialize<'de, D: Deserializer<'de>>(d: D) -> Result<Duration, D::Error> {
+        serde_ext_duration::heuristic::deserialize_with_threshold(d, 1000)
+    }
+}
+
+#[derive(Deserialize, Debug)]
+struct Small {
+    #[serde(deserialize_with = "ms_from_1000::deserialize")]
+    t: Duration,
+}
+
+fn read(json: &str) -> Duration {
+    serde_json::from_str::<Rec>(&format!(r#"{{"t":{json}}}"#)).unwrap().t
+}
+
+#[test]
+fn magnitude_picks_the_unit() {
+    assert_eq!(read("90"), Duration::from_secs(90));
+    assert_eq!(read("99999999999"), Duration::from_secs(99_999_999_999));
+    assert_eq!(read("100000000000"), Duration::from_millis(100_000_000_000));
+    assert_eq!(read("1700000000000"), Duration::from_millis(1_700_000_000_000));
+    assert_eq!(read(r#""1700000000000""#), Duration::from_millis(1_700_000_000_000));
+    assert_eq!(read(r#""90""#), Duration::from_secs(90));
+}
+
+#[test]
+fn other_input_is_unchanged() {
+    assert_eq!(read("1.5"), Duration::from_millis(1500));
+    assert_eq!(read(r#""1h""#), Duration::from_secs(3600));
+    assert!(serde_json::from_str::<Rec>(r#"{"t":-1}"#).is_err());
+}
+
+#[test]
+fn custom_threshold() {
+    let v: Small = serde_json::from_str(r#"{"t":999}"#).unwrap();
+    assert_eq!(v.t, Duration::from_secs(999));
+    let v: Small = serde_json::from_str(r#"{"t":1000}"#).unwrap();
+    assert_eq!(v.t, Duration::from_secs(1));
+}
+
+#[test]
+fn writes_human_that_reads_back() {
+    for t in [Duration::from_secs(90), Duration::from_millis(1_700_000_000_000)] {
+        let s = serde_json::to_string(&Rec { t }).unwrap();
+        assert_eq!(serde_json::from_str::<Rec>(&s).unwrap().t, t);
+    }
+    assert_eq!(serde_json::to_string(&Rec { t: Duration::from_secs(90) }).unwrap(), r#"{"t":"1m 30s"}"#);
+}
@@ -0,0 +1,38 @@
+use serde::{Deserialize, Serialize};
+use serde_ext_duration::http_delta_seconds::MAX;
+use std::time::Duration;
+
+#[derive(Serialize, Deserialize, Debug)]
+struct CacheControl {
+    #[serde(with = "serde_ext_duration::http_delta_seconds")]
+    max_age: Duration,
+}
+
+#[test]
+fn emits_whole_seconds_clamped_to_2_pow_31() {
+    let c = CacheControl { max_age: Duration::from_millis(3_600_900) };
+    assert_eq!(serde_json::to_string(&c).unwrap(), r#"{"max_age":3600}"#);
+    let year = Duration::from_secs(366 * 86_400);
+    assert_eq!(serde_json::to_string(&CacheControl { max_age: year }).unwrap(), r#"{"max_age":31622400}"#);
+    let century = Duration::from_secs(100 * 366 * 86_400);
+    assert_eq!(serde_json::to_string(&CacheControl { max_age: century }).unwrap(), r#"{"max_age":2147483648}"#);
+}
+
+#[test]
+fn reads_integers_and_digit_strings() {
+    let c: CacheControl = serde_json::from_str(r#"{"max_age":"600"}"#).unwrap();
+    assert_eq!(c.max_age, Duration::from_secs(600));
+    let c: CacheControl = serde_json::from_str(r#"{"max_age":99999999999}"#).unwrap();
+    assert_eq!(c.max_age, Duration::from_secs(MAX));
+    let c: CacheControl = serde_json::from_str(r#"{"max_age":"99999999999999999999999"}"#).unwrap();
+    assert_eq!(c.max_age, Duration::from_secs(MAX));
+    let c: CacheControl = serde_json::from_str(r#"{"max_age":99999999999999999999999}"#).unwrap();
+    assert_eq!(c.max_age, Duration::from_secs(MAX));
+    let err = serde_json::from_str::<CacheControl>(r#"{"max_age":1.5}"#).unwrap_err();
+    assert!(err.to_string().contains("expected delta-seconds"));
+    let err = serde_json::from_str::<CacheControl>(r#"{"max_age":"1.5"}"#).unwrap_err();
+    assert!(!err.to_string().contains("1m 30s"));
+    assert!(serde_json::from_str::<CacheControl>(r#"{"max_age":-1}"#).is_err());
+    let err = serde_json::from_str::<CacheControl>(r#"{"max_age":"5m"}"#).unwrap_err();
+    assert!(err.to_string().contains("expected delta-seconds"));
+}
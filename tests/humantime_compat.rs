@@ -0,0 +1,142 @@
+//! Every string example from humantime's `parse_duration` docs and tests parses to the same value.
+use serde_ext_duration::parse_str;
+use std::time::Duration;
+
+fn check(cases: &[(&str, Duration)]) {
+    for &(s, expected) in cases {
+        assert_eq!(parse_str(s), Ok(expected), "{s}");
+    }
+}
+
+#[test]
+fn units() {
+    let secs = Duration::from_secs;
+    check(&[
+        ("17nsec", Duration::new(0, 17)),
+        ("17nanos", Duration::new(0, 17)),
+        ("33ns", Duration::new(0, 33)),
+        ("3usec", Duration::new(0, 3000)),
+        ("78us", Duration::new(0, 78000)),
+        ("163µs", Duration::new(0, 163000)),
+        ("31msec", Duration::new(0, 31_000_000)),
+        ("31millis", Duration::new(0, 31_000_000)),
+        ("6ms", Duration::new(0, 6_000_000)),
+        ("3000s", secs(3000)),
+        ("300sec", secs(300)),
+        ("300secs", secs(300)),
+        ("50seconds", secs(50)),
+        ("1second", secs(1)),
+        ("100m", secs(6000)),
+        ("12min", secs(720)),
+        ("12mins", secs(720)),
+        ("1minute", secs(60)),
+        ("7minutes", secs(420)),
+        ("2h", secs(7200)),
+        ("7hr", secs(25200)),
+        ("7hrs", secs(25200)),
+        ("1hour", secs(3600)),
+        ("24hours", secs(86400)),
+        ("1day", secs(86400)),
+        ("2days", secs(172_800)),
+        ("365d", secs(31_536_000)),
+        ("1week", secs(604_800)),
+        ("7weeks", secs(4_233_600)),
+        ("104wks", secs(2 * 31_449_600)),
+        ("100wk", secs(60_480_000)),
+        ("52w", secs(31_449_600)),
+        ("1month", secs(2_630_016)),
+        ("3months", secs(3 * 2_630_016)),
+        ("1year", secs(31_557_600)),
+        ("7years", secs(7 * 31_557_600)),
+        ("15yrs", secs(15 * 31_557_600)),
+        ("10yr", secs(10 * 31_557_600)),
+        ("17y", secs(536_479_200)),
+    ]);
+    // humantime's `M` (months) is case-sensitive; here it is minutes, like `m`
+    assert_eq!(parse_str("12M"), Ok(secs(720)));
+}
+
+#[test]
+fn fractions() {
+    let secs = Duration::from_secs;
+    check(&[
+        ("3.1usec", Duration::new(0, 3100)),
+        ("3.1us", Duration::new(0, 3100)),
+        ("3.01us", Duration::new(0, 3010)),
+        ("3.001us", Duration::new(0, 3001)),
+        ("31.1msec", Duration::new(0, 31_100_000)),
+        ("31.1millis", Duration::new(0, 31_100_000)),
+        ("31.1ms", Duration::new(0, 31_100_000)),
+        ("31.01ms", Duration::new(0, 31_010_000)),
+        ("31.001ms", Duration::new(0, 31_001_000)),
+        ("31.0001ms", Duration::new(0, 31_000_100)),
+        ("31.00001ms", Duration::new(0, 31_000_010)),
+        ("31.000001ms", Duration::new(0, 31_000_001)),
+        ("300.0sec", secs(300)),
+        ("300.0secs", secs(300)),
+        ("300.0seconds", secs(300)),
+        ("300.0s", secs(300)),
+        ("0.0s", secs(0)),
+        ("0.2s", Duration::new(0, 200_000_000)),
+        ("1.2s", Duration::new(1, 200_000_000)),
+        ("1.02s", Duration::new(1, 20_000_000)),
+        ("1.002s", Duration::new(1, 2_000_000)),
+        ("1.000000002s", Duration::new(1, 2)),
+        ("1.123456789s", Duration::new(1, 123_456_789)),
+        ("100.0m", secs(6000)),
+        ("12.1min", secs(726)),
+        ("12.1mins", secs(726)),
+        ("1.5minute", secs(90)),
+        ("1.5minutes", secs(90)),
+        ("2.0h", secs(7200)),
+        ("2.0hr", secs(7200)),
+        ("2.0hrs", secs(7200)),
+        ("2.0hours", secs(7200)),
+        ("2.5h", secs(9000)),
+        ("0.5h", secs(1800)),
+        ("1.5day", secs(86400 + 86400 / 2)),
+        ("1.5days", secs(86400 + 86400 / 2)),
+        ("1.5d", secs(86400 + 86400 / 2)),
+    ]);
+}
+
+#[test]
+fn combinations() {
+    check(&[
+        ("7.120us 3ns", Duration::new(0, 7123)),
+        ("7.123us 4ns", Duration::new(0, 7127)),
+        ("1.234s 789ns", Duration::new(1, 234_000_789)),
+        ("1.234s 0.789us", Duration::new(1, 234_000_789)),
+        ("1.234567s 0.789us", Duration::new(1, 234_567_789)),
+        ("1.234s 1.345ms 1.678us 1ns", Duration::new(1, 235_346_679)),
+        ("1.234s 0.345ms 0.678us 0ns", Duration::new(1, 234_345_678)),
+        ("1.234s0.345ms0.678us0ns", Duration::new(1, 234_345_678)),
+        ("0", Duration::ZERO),
+        ("20 min 17 nsec ", Duration::new(1200, 17)),
+        ("2h 15m", Duration::new(8100, 0)),
+        ("2h 37min", Duration::new(9420, 0)),
+        ("32ms", Duration::new(0, 32_000_000)),
+        ("4.2s", Duration::new(4, 200_000_000)),
+        ("1h30m", Duration::new(5400, 0)),
+    ]);
+}
+
+#[test]
+fn humantime_formatted_output_parses() {
+    // shape of `humantime::format_duration`, largest unit first
+    check(&[
+        ("1year 2months 3days 4h 5m 6s 7ms 8us 9ns", {
+            let secs = 31_557_600 + 2 * 2_630_016 + 3 * 86_400 + 4 * 3600 + 5 * 60 + 6;
+            Duration::new(secs, 7_008_009)
+        }),
+        ("2h 37m", Duration::from_secs(9420)),
+        ("1µs", Duration::from_micros(1)),
+    ]);
+}
+
+#[test]
+fn malformed_numbers_still_fail() {
+    for bad in ["1.s", "1..s", ".1s", ".", "123"] {
+        assert!(parse_str(bad).is_err(), "{bad}");
+    }
+}
@@ -0,0 +1,44 @@
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct Api {
+    #[serde(with = "serde_ext_duration::interop")]
+    ttl: Duration,
+}
+
+fn read(json: &str) -> Result<Duration, String> {
+    serde_json::from_str::<Api>(&format!(r#"{{"ttl":{json}}}"#)).map(|a| a.ttl).map_err(|e| e.to_string())
+}
+
+#[test]
+fn writes_both_fields() {
+    let s = serde_json::to_string(&Api { ttl: Duration::from_secs(5400) }).unwrap();
+    assert_eq!(s, r#"{"ttl":{"iso":"PT1H30M","seconds":5400}}"#);
+    let s = serde_json::to_string(&Api { ttl: Duration::from_millis(1500) }).unwrap();
+    assert_eq!(s, r#"{"ttl":{"iso":"PT1.5S","seconds":1}}"#);
+    assert_eq!(serde_json::from_str::<Api>(&s).unwrap().ttl, Duration::from_millis(1500));
+}
+
+#[test]
+fn iso_wins_over_seconds() {
+    assert_eq!(read(r#"{"iso":"PT1H","seconds":60}"#).unwrap(), Duration::from_secs(3600));
+    assert_eq!(read(r#"{"seconds":60,"iso":"PT1H"}"#).unwrap(), Duration::from_secs(3600));
+    assert_eq!(read(r#"{"iso":"PT1H"}"#).unwrap(), Duration::from_secs(3600));
+}
+
+#[test]
+fn seconds_is_the_fallback() {
+    assert_eq!(read(r#"{"seconds":5400}"#).unwrap(), Duration::from_secs(5400));
+    assert_eq!(read(r#"{"seconds":"90m"}"#).unwrap(), Duration::from_secs(5400));
+}
+
+#[test]
+fn malformed_objects_are_rejected() {
+    assert!(read(r#"{}"#).unwrap_err().contains("missing field `iso`"));
+    assert!(read(r#"{"iso":"1h"}"#).unwrap_err().contains("ISO 8601"));
+    assert!(read(r#"{"iso":"PT1H","seconds":-1}"#).unwrap_err().contains("negative"));
+    assert!(read(r#"{"iso":"PT1H","iso":"PT2H"}"#).unwrap_err().contains("duplicate field `iso`"));
+    assert!(read(r#"{"iso":"PT1H","minutes":60}"#).unwrap_err().contains("unknown field `minutes`"));
+    assert!(read(r#""PT1H""#).is_err());
+}
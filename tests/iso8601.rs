@@ -0,0 +1,151 @@
+use serde::{Deserialize, Serialize};
+use serde_ext_duration::iso8601::{parse, to_iso8601_string, to_iso8601_string_with_precision};
+use std::time::Duration;
+
+#[derive(Serialize, Deserialize, Debug)]
+struct Cfg {
+    #[serde(with = "serde_ext_duration::iso8601")]
+    t: Duration,
+}
+
+#[test]
+fn formats_canonical_iso() {
+    let secs = Duration::from_secs;
+    assert_eq!(to_iso8601_string(&Duration::ZERO), "PT0S");
+    assert_eq!(to_iso8601_string(&secs(5400)), "PT1H30M");
+    assert_eq!(to_iso8601_string(&secs(2 * 86_400 + 3 * 3600)), "P2DT3H");
+    assert_eq!(to_iso8601_string(&secs(86_400)), "P1D");
+    assert_eq!(to_iso8601_string(&Duration::from_millis(250)), "PT0.25S");
+    assert_eq!(to_iso8601_string(&Duration::new(61, 5)), "PT1M1.000000005S");
+}
+
+#[test]
+fn parses_iso_forms() {
+    let secs = Duration::from_secs;
+    assert_eq!(parse("PT1H30M").unwrap(), secs(5400));
+    assert_eq!(parse("P2DT3H").unwrap(), secs(2 * 86_400 + 3 * 3600));
+    assert_eq!(parse("P1W").unwrap(), secs(7 * 86_400));
+    assert_eq!(parse("pt1m30s").unwrap(), secs(90));
+    assert_eq!(parse("PT0,5S").unwrap(), Duration::from_millis(500));
+    assert_eq!(parse("PT1.5H").unwrap(), secs(5400));
+    assert_eq!(parse(" PT0S ").unwrap(), Duration::ZERO);
+}
+
+#[test]
+fn rejects_invalid_iso() {
+    assert!(parse("P1Y").unwrap_err().contains("no fixed length"));
+    assert!(parse("P1M").unwrap_err().contains("no fixed length"));
+    assert!(parse("PT1M1H").unwrap_err().contains("out-of-order"));
+    assert!(parse("PT1.5H30M").unwrap_err().contains("last component"));
+    for bad in ["", "P", "PT", "P1DT", "1H", "PT1", "PTH", "PT1.S", "PT1H1H"] {
+        assert!(parse(bad).is_err(), "{bad}");
+    }
+}
+
+#[test]
+fn round_trips() {
+    for d in [
+        Duration::ZERO,
+        Duration::from_millis(1),
+        Duration::from_secs(5400),
+        Duration::new(3 * 86_400 + 59, 999_999_999),
+        Duration::MAX,
+    ] {
+        assert_eq!(parse(&to_iso8601_string(&d)).unwrap(), d);
+        let j = serde_json::to_string(&Cfg { t: d }).unwrap();
+        assert_eq!(serde_json::from_str::<Cfg>(&j).unwrap().t, d);
+    }
+    let v: Cfg = serde_json::from_str(r#"{"t":90}"#).unwrap();
+    assert_eq!(v.t, Duration::from_secs(90));
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct TimeOnly {
+    #[serde(with = "serde_ext_duration::iso8601::time")]
+    t: Duration,
+}
+
+#[test]
+fn time_only_keeps_days_in_hours() {
+    use serde_ext_duration::iso8601::time::to_iso8601_string as time_only;
+    assert_eq!(time_only(&Duration::from_secs(2 * 86_400)), "PT48H");
+    assert_eq!(time_only(&Duration::from_secs(86_400 + 90)), "PT24H1M30S");
+    assert_eq!(time_only(&Duration::from_millis(250)), "PT0.25S");
+    assert_eq!(time_only(&Duration::ZERO), "PT0S");
+    assert_eq!(parse(&time_only(&Duration::MAX)).unwrap(), Duration::MAX);
+
+    let j = serde_json::to_string(&TimeOnly { t: Duration::from_secs(3 * 86_400) }).unwrap();
+    assert_eq!(j, r#"{"t":"PT72H"}"#);
+    let v: TimeOnly = serde_json::from_str(r#"{"t":"P3D"}"#).unwrap();
+    assert_eq!(v.t, Duration::from_secs(3 * 86_400));
+}
+
+#[test]
+fn fixed_precision_pads_or_drops_the_fraction() {
+    let d = Duration::from_millis(250);
+    assert_eq!(to_iso8601_string_with_precision(&d, 0), "PT0S");
+    assert_eq!(to_iso8601_string_with_precision(&d, 3), "PT0.250S");
+    assert_eq!(to_iso8601_string_with_precision(&d, 6), "PT0.250000S");
+    assert_eq!(to_iso8601_string_with_precision(&d, 9), "PT0.250000000S");
+    // the default stays minimal
+    assert_eq!(to_iso8601_string(&d), "PT0.25S");
+
+    // seconds are always present once a fraction is fixed
+    assert_eq!(to_iso8601_string_with_precision(&Duration::from_secs(3600), 3), "PT1H0.000S");
+    assert_eq!(to_iso8601_string_with_precision(&Duration::from_secs(86_400), 3), "P1DT0.000S");
+    assert_eq!(to_iso8601_string_with_precision(&Duration::ZERO, 3), "PT0.000S");
+    assert_eq!(to_iso8601_string_with_precision(&Duration::from_secs(3600), 0), "PT1H");
+}
+
+#[test]
+fn fixed_precision_rounds_half_up_with_carry() {
+    assert_eq!(to_iso8601_string_with_precision(&Duration::new(1, 500_000_000), 0), "PT2S");
+    assert_eq!(to_iso8601_string_with_precision(&Duration::new(59, 999_600_000), 3), "PT1M0.000S");
+    assert_eq!(to_iso8601_string_with_precision(&Duration::new(0, 1_234_567), 6), "PT0.001235S");
+    assert_eq!(to_iso8601_string_with_precision(&Duration::new(0, 1_234_567), 9), "PT0.001234567S");
+    // rounding never goes past `Duration::MAX`
+    assert!(parse(&to_iso8601_string_with_precision(&Duration::MAX, 0)).is_ok());
+}
+
+#[test]
+fn any_precision_parses_back() {
+    for digits in [0, 3, 6, 9] {
+        let d = Duration::from_millis(93_784_250);
+        let s = to_iso8601_string_with_precision(&d, digits);
+        let expected = if digits == 0 { Duration::from_secs(93_784) } else { d };
+        assert_eq!(parse(&s).unwrap(), expected, "{s}");
+    }
+}
+
+#[test]
+#[should_panic(expected = "at most 9 fractional digits")]
+fn precision_above_nine_panics() {
+    to_iso8601_string_with_precision(&Duration::ZERO, 10);
+}
+
+#[test]
+fn strict_order_versus_lenient() {
+    use serde_ext_duration::iso8601::lenient;
+    let ninety = Duration::from_secs(90 * 60);
+    assert!(parse("PT30M1H").unwrap_err().contains("out-of-order"));
+    assert!(parse("PT1H1H").unwrap_err().contains("out-of-order"));
+    assert!(parse("P1D1W").unwrap_err().contains("out-of-order"));
+    assert_eq!(parse("PT1H30M").unwrap(), ninety);
+
+    assert_eq!(lenient::parse("PT30M1H").unwrap(), ninety);
+    assert_eq!(lenient::parse("PT1H30M").unwrap(), ninety);
+    assert_eq!(lenient::parse("P1D1WT5S30M").unwrap(), Duration::from_secs(8 * 86_400 + 1805));
+    assert_eq!(lenient::parse("PT1H1H").unwrap_err(), "duplicate designator 'H' in 'PT1H1H'");
+    // time designators still need the `T`, and a fraction still ends the input
+    assert!(lenient::parse("P1H").is_err());
+    assert!(lenient::parse("PT0.5M1H").is_err());
+
+    #[derive(Deserialize)]
+    struct Lenient {
+        #[serde(with = "serde_ext_duration::iso8601::lenient")]
+        t: Duration,
+    }
+    let v: Lenient = serde_json::from_str(r#"{"t":"PT30S2M"}"#).unwrap();
+    assert_eq!(v.t, Duration::from_secs(150));
+    assert!(serde_json::from_str::<Cfg>(r#"{"t":"PT30S2M"}"#).is_err());
+}
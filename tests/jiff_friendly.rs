@@ -0,0 +1,71 @@
+#![cfg(feature = "jiff")]
+
+use jiff::{SignedDuration, Span};
+use serde::{Deserialize, Serialize};
+use serde_ext_duration::jiff_friendly::{parse, to_jiff_string};
+use serde_ext_duration::{parse_str, to_human_string};
+use std::time::Duration;
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct Cfg {
+    #[serde(with = "serde_ext_duration::jiff_friendly")]
+    t: Duration,
+}
+
+const SAMPLES: [Duration; 6] = [
+    Duration::ZERO,
+    Duration::from_millis(250),
+    Duration::from_secs(5400),
+    Duration::new(5400, 250_000_001),
+    Duration::from_secs(3 * 86_400 + 1),
+    Duration::from_micros(1_500),
+];
+
+#[test]
+fn output_matches_jiff() {
+    assert_eq!(to_jiff_string(&Duration::from_secs(5400)).unwrap(), "1h 30m");
+    assert_eq!(to_jiff_string(&Duration::from_secs(3 * 86_400 + 1)).unwrap(), "72h 1s");
+    for d in SAMPLES {
+        let s = to_jiff_string(&d).unwrap();
+        assert_eq!(s, format!("{:#}", SignedDuration::try_from(d).unwrap()));
+        let back: SignedDuration = s.parse().unwrap();
+        assert_eq!(Duration::try_from(back).unwrap(), d, "{s}");
+        // our own parser reads it as well
+        assert_eq!(parse_str(&s).unwrap(), d, "{s}");
+    }
+    assert_eq!(to_jiff_string(&Duration::MAX), Err(serde_ext_duration::SerializeDurationError::TooLarge));
+}
+
+#[test]
+fn human_output_parses_in_jiff() {
+    for d in SAMPLES {
+        let s = to_human_string(&d);
+        assert!(s.parse::<Span>().is_ok(), "{s}");
+        if d < Duration::from_secs(86_400) {
+            let sd: SignedDuration = s.parse().unwrap();
+            assert_eq!(Duration::try_from(sd).unwrap(), parse_str(&s).unwrap(), "{s}");
+        } else {
+            assert!(s.parse::<SignedDuration>().is_err(), "{s}");
+        }
+    }
+}
+
+#[test]
+fn parses_with_jiff() {
+    assert_eq!(parse("1h 30m").unwrap(), Duration::from_secs(5400));
+    assert_eq!(parse("1 hour, 30 minutes").unwrap(), Duration::from_secs(5400));
+    assert_eq!(parse("PT1H30M").unwrap(), Duration::from_secs(5400));
+    assert_eq!(parse("-1h").unwrap_err(), "negative duration not allowed");
+    assert!(parse("2d").is_err());
+    assert!(parse("soon").is_err());
+}
+
+#[test]
+fn serde_roundtrip() {
+    let c = Cfg { t: Duration::new(5400, 250_000_001) };
+    let s = serde_json::to_string(&c).unwrap();
+    assert_eq!(s, r#"{"t":"1h 30m 250ms 1ns"}"#);
+    assert_eq!(serde_json::from_str::<Cfg>(&s).unwrap(), c);
+    assert_eq!(serde_json::from_str::<Cfg>(r#"{"t":90}"#).unwrap().t, Duration::from_secs(90));
+    assert!(serde_json::to_string(&Cfg { t: Duration::MAX }).is_err());
+}
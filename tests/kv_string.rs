@@ -0,0 +1,43 @@
+use serde::{Deserialize, Serialize};
+use serde_ext_duration::kv_string::{parse, to_kv_string};
+use std::time::Duration;
+
+#[derive(Serialize, Deserialize, Debug)]
+struct Cfg {
+    #[serde(with = "serde_ext_duration::kv_string")]
+    t: Duration,
+}
+
+#[test]
+fn sums_unit_value_pairs() {
+    assert_eq!(parse("h=1 m=30").unwrap(), Duration::from_secs(90 * 60));
+    assert_eq!(parse("h=1 m=30 s=15").unwrap(), Duration::from_secs(5415));
+    assert_eq!(parse("  s=15\tH=1  ").unwrap(), Duration::from_secs(3615));
+    assert_eq!(parse("h=1.5").unwrap(), Duration::from_secs(5400));
+    assert_eq!(parse("d=1 ms=250").unwrap(), Duration::from_millis(86_400_250));
+    let v: Cfg = serde_json::from_str(r#"{"t":"m=2"}"#).unwrap();
+    assert_eq!(v.t, Duration::from_secs(120));
+}
+
+#[test]
+fn rejects_unknown_keys_and_bad_pairs() {
+    assert_eq!(parse("x=1").unwrap_err(), "unknown unit 'x' (this format takes d, h, m, s, ms)");
+    assert_eq!(parse("hours=1").unwrap_err(), "unknown unit 'hours' (this format takes d, h, m, s, ms)");
+    assert_eq!(parse("h=1 h=2").unwrap_err(), "duplicate unit 'h'");
+    assert_eq!(parse("h1").unwrap_err(), "expected unit=number, got 'h1'");
+    assert_eq!(parse("h=").unwrap_err(), "invalid number '' for unit 'h'");
+    assert!(parse("h=1.").is_err());
+    assert!(parse("h = 1").is_err());
+    assert_eq!(parse("").unwrap_err(), "empty duration string");
+}
+
+#[test]
+fn serializes_largest_unit_first() {
+    assert_eq!(to_kv_string(&Duration::from_secs(90 * 60)), "h=1 m=30");
+    assert_eq!(to_kv_string(&Duration::from_millis(86_400_250)), "d=1 ms=250");
+    assert_eq!(to_kv_string(&Duration::ZERO), "s=0");
+    let json = serde_json::to_string(&Cfg { t: Duration::from_secs(5415) }).unwrap();
+    assert_eq!(json, r#"{"t":"h=1 m=30 s=15"}"#);
+    let back: Cfg = serde_json::from_str(&json).unwrap();
+    assert_eq!(back.t, Duration::from_secs(5415));
+}
@@ -0,0 +1,43 @@
+use serde::{Deserialize, Serialize};
+use serde_ext_duration::latency::to_latency_string;
+use serde_ext_duration::parse_str;
+use std::time::Duration;
+
+#[derive(Serialize, Deserialize, Debug)]
+struct Sample {
+    #[serde(with = "serde_ext_duration::latency")]
+    p99: Duration,
+}
+
+#[test]
+fn single_unit_three_significant_digits() {
+    let ns = Duration::from_nanos;
+    assert_eq!(to_latency_string(&ns(850)), "850ns");
+    assert_eq!(to_latency_string(&ns(1500)), "1.5µs");
+    assert_eq!(to_latency_string(&ns(250_000)), "250µs");
+    assert_eq!(to_latency_string(&ns(1_500_000)), "1.5ms");
+    assert_eq!(to_latency_string(&ns(12_345_678)), "12.3ms");
+    assert_eq!(to_latency_string(&ns(2_054_000_000)), "2.05s");
+    assert_eq!(to_latency_string(&ns(1_000)), "1µs");
+    assert_eq!(to_latency_string(&ns(1_234)), "1.23µs");
+    assert_eq!(to_latency_string(&Duration::from_secs(5400)), "5400s");
+    assert_eq!(to_latency_string(&Duration::ZERO), "0s");
+}
+
+#[test]
+fn rounding_promotes_to_the_next_unit() {
+    assert_eq!(to_latency_string(&Duration::from_nanos(999_700)), "1ms");
+    assert_eq!(to_latency_string(&Duration::from_nanos(9_996)), "10µs");
+    assert_eq!(to_latency_string(&Duration::from_nanos(999_999_999)), "1s");
+}
+
+#[test]
+fn output_parses_back() {
+    for n in [850, 1500, 250_000, 1_500_000, 2_050_000_000] {
+        let d = Duration::from_nanos(n);
+        assert_eq!(parse_str(&to_latency_string(&d)).unwrap(), d);
+    }
+    let j = serde_json::to_string(&Sample { p99: Duration::from_micros(1500) }).unwrap();
+    assert_eq!(j, r#"{"p99":"1.5ms"}"#);
+    assert_eq!(serde_json::from_str::<Sample>(&j).unwrap().p99, Duration::from_micros(1500));
+}
@@ -0,0 +1,44 @@
+use serde::Deserialize;
+use serde_ext_duration::lenient::parse;
+use std::time::Duration;
+
+#[derive(Deserialize, Debug)]
+struct Form {
+    #[serde(with = "serde_ext_duration::lenient")]
+    t: Duration,
+}
+
+#[test]
+fn trailing_punctuation_is_ignored() {
+    assert_eq!(parse("1h;").unwrap(), Duration::from_secs(3600));
+    assert_eq!(parse("30m.").unwrap(), Duration::from_secs(1800));
+    assert_eq!(parse("1h 30m, ").unwrap(), Duration::from_secs(5400));
+    assert_eq!(parse("5s.;").unwrap(), Duration::from_secs(5));
+    assert_eq!(parse("1.5h.").unwrap(), Duration::from_secs(5400));
+
+    let v: Form = serde_json::from_str(r#"{"t":"30m."}"#).unwrap();
+    assert_eq!(v.t, Duration::from_secs(1800));
+}
+
+#[test]
+fn leading_tilde_is_ignored() {
+    assert_eq!(parse("~1h 30m").unwrap(), Duration::from_secs(5400));
+    assert_eq!(parse(" ~ 30m").unwrap(), Duration::from_secs(1800));
+    assert_eq!(parse("~2h.").unwrap(), Duration::from_secs(7200));
+    let v: Form = serde_json::from_str(r#"{"t":"~30m"}"#).unwrap();
+    assert_eq!(v.t, Duration::from_secs(1800));
+
+    assert!(serde_ext_duration::parse_str("~30m").is_err());
+    assert!(parse("~~30m").is_err());
+    assert!(parse("30m~").is_err());
+    assert!(parse("~").is_err());
+}
+
+#[test]
+fn strict_elsewhere() {
+    assert!(serde_ext_duration::parse_str("1h;").is_err());
+    assert!(parse("1h; 30m").is_err());
+    assert!(parse(";").is_err());
+    assert!(parse("30.").is_err());
+    assert!(parse("!1h").is_err());
+}
@@ -0,0 +1,51 @@
+use serde::{Deserialize, Serialize};
+use serde_ext_duration::log_format::{parse, to_log_string};
+use std::time::Duration;
+
+#[derive(Serialize, Deserialize, Debug)]
+struct Line {
+    #[serde(with = "serde_ext_duration::log_format")]
+    t: Duration,
+}
+
+#[test]
+fn sub_day_values_have_no_days_prefix() {
+    assert_eq!(to_log_string(&Duration::from_millis(7_384_250)), "02:03:04.250");
+    assert_eq!(to_log_string(&Duration::ZERO), "00:00:00.000");
+    assert_eq!(to_log_string(&Duration::from_secs(86_399)), "23:59:59.000");
+    // milliseconds round half-up and carry
+    assert_eq!(to_log_string(&Duration::new(59, 999_500_000)), "00:01:00.000");
+}
+
+#[test]
+fn multi_day_values_get_a_days_prefix() {
+    assert_eq!(to_log_string(&Duration::from_millis(93_784_250)), "1d 02:03:04.250");
+    assert_eq!(to_log_string(&Duration::from_secs(86_400)), "1d 00:00:00.000");
+    assert_eq!(to_log_string(&Duration::from_secs(12 * 86_400 + 5)), "12d 00:00:05.000");
+}
+
+#[test]
+fn exact_seconds_keep_the_millis() {
+    let json = serde_json::to_string(&Line { t: Duration::from_secs(5) }).unwrap();
+    assert_eq!(json, r#"{"t":"00:00:05.000"}"#);
+    let back: Line = serde_json::from_str(&json).unwrap();
+    assert_eq!(back.t, Duration::from_secs(5));
+}
+
+#[test]
+fn parses_its_own_output() {
+    assert_eq!(parse("02:03:04.250").unwrap(), Duration::from_millis(7_384_250));
+    assert_eq!(parse("1d 02:03:04.250").unwrap(), Duration::from_millis(93_784_250));
+    let v: Line = serde_json::from_str(r#"{"t":"3d 00:00:00.001"}"#).unwrap();
+    assert_eq!(v.t, Duration::from_millis(3 * 86_400_000 + 1));
+}
+
+#[test]
+fn rejects_anything_off_template() {
+    assert_eq!(parse("2:03:04.250").unwrap_err(), "expected [Dd ]HH:MM:SS.mmm");
+    assert_eq!(parse("02:03:04").unwrap_err(), "expected [Dd ]HH:MM:SS.mmm");
+    assert_eq!(parse("xd 02:03:04.250").unwrap_err(), "expected [Dd ]HH:MM:SS.mmm");
+    assert_eq!(parse("24:00:00.000").unwrap_err(), "clock field out of range in '24:00:00.000'");
+    assert!(parse("00:60:00.000").is_err());
+    assert!(parse("00:00:00.0000").is_err());
+}
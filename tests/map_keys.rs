@@ -0,0 +1,44 @@
+use serde::{Deserialize, Serialize};
+use serde_ext_duration::ExtDuration;
+use std::collections::{BTreeMap, HashMap};
+use std::time::Duration;
+
+#[derive(Serialize, Deserialize, Debug)]
+struct Tiers {
+    #[serde(with = "serde_ext_duration::map_keys")]
+    tiers: BTreeMap<Duration, String>,
+}
+
+#[derive(Deserialize, Debug)]
+struct HashTiers {
+    #[serde(with = "serde_ext_duration::map_keys")]
+    tiers: HashMap<Duration, u32>,
+}
+
+#[test]
+fn duration_keys_round_trip() {
+    let v: Tiers = serde_json::from_str(r#"{ "tiers": { "30s": "fast", "5m": "slow" } }"#).unwrap();
+    assert_eq!(v.tiers[&Duration::from_secs(30)], "fast");
+    assert_eq!(v.tiers[&Duration::from_secs(300)], "slow");
+
+    let j = serde_json::to_string(&v).unwrap();
+    assert_eq!(j, r#"{"tiers":{"30s":"fast","5m":"slow"}}"#);
+}
+
+#[test]
+fn duration_keys_flexible_and_errors() {
+    let v: HashTiers = serde_yaml::from_str("tiers:\n  90: 1\n  '1h 30m': 2\n").unwrap();
+    assert_eq!(v.tiers[&Duration::from_secs(90)], 1);
+    assert_eq!(v.tiers[&Duration::from_secs(5400)], 2);
+
+    let err = serde_json::from_str::<HashTiers>(r#"{ "tiers": { "5q": 1 } }"#).unwrap_err();
+    assert!(err.to_string().contains("unknown unit"));
+}
+
+#[test]
+fn ext_duration_as_plain_key() {
+    let m: HashMap<ExtDuration, bool> = serde_json::from_str(r#"{ "250ms": true }"#).unwrap();
+    assert!(m[&ExtDuration(Duration::from_millis(250))]);
+    let j = serde_json::to_string(&m).unwrap();
+    assert_eq!(j, r#"{"250ms":true}"#);
+}
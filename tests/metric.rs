@@ -0,0 +1,36 @@
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+#[derive(Serialize, Deserialize, Debug)]
+struct Sample {
+    #[serde(with = "serde_ext_duration::metric")]
+    latency: Duration,
+}
+
+#[test]
+fn metric_serializes_value_and_label() {
+    let j = serde_json::to_string(&Sample { latency: Duration::from_millis(65_250) }).unwrap();
+    assert_eq!(j, r#"{"latency":{"seconds":65.25,"human":"1m 5s 250ms"}}"#);
+    let back: Sample = serde_json::from_str(&j).unwrap();
+    assert_eq!(back.latency, Duration::from_millis(65_250));
+}
+
+#[test]
+fn metric_accepts_either_field_seconds_wins() {
+    let v: Sample = serde_json::from_str(r#"{"latency":{"human":"1m 5s"}}"#).unwrap();
+    assert_eq!(v.latency, Duration::from_secs(65));
+    let v: Sample = serde_json::from_str(r#"{"latency":{"seconds":2.5}}"#).unwrap();
+    assert_eq!(v.latency, Duration::from_millis(2500));
+    let v: Sample = serde_json::from_str(r#"{"latency":{"human":"1h","seconds":3}}"#).unwrap();
+    assert_eq!(v.latency, Duration::from_secs(3));
+    let v: Sample = serde_json::from_str(r#"{"latency":"250ms"}"#).unwrap();
+    assert_eq!(v.latency, Duration::from_millis(250));
+}
+
+#[test]
+fn metric_rejects_bad_objects() {
+    let err = serde_json::from_str::<Sample>(r#"{"latency":{}}"#).unwrap_err();
+    assert!(err.to_string().contains("missing field `seconds`"));
+    let err = serde_json::from_str::<Sample>(r#"{"latency":{"value":1}}"#).unwrap_err();
+    assert!(err.to_string().contains("unknown field"));
+}
@@ -0,0 +1,67 @@
+use serde::{Deserialize, Serialize};
+use serde_ext_duration::millis_padded::to_padded_string;
+use serde_ext_duration::SerializeDurationError;
+use std::time::Duration;
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct Key {
+    #[serde(with = "serde_ext_duration::millis_padded")]
+    t: Duration,
+}
+
+fn pad6<S: serde::Serializer>(d: &Duration, s: S) -> Result<S::Ok, S::Error> {
+    serde_ext_duration::millis_padded::serialize_with_width(d, 6, s)
+}
+
+#[derive(Serialize)]
+struct Short {
+    #[serde(serialize_with = "pad6")]
+    t: Duration,
+}
+
+#[test]
+fn pads_to_eleven_digits() {
+    let s = serde_json::to_string(&Key { t: Duration::from_secs(65) }).unwrap();
+    assert_eq!(s, r#"{"t":"00000065000"}"#);
+    assert_eq!(to_padded_string(&Duration::ZERO, 11).unwrap(), "00000000000");
+    assert_eq!(to_padded_string(&Duration::from_micros(1500), 11).unwrap(), "00000000002");
+    assert_eq!(to_padded_string(&Duration::from_millis(99_999_999_999), 11).unwrap(), "99999999999");
+}
+
+#[test]
+fn round_trip() {
+    for ms in [0, 1, 250, 65_000, 99_999_999_999] {
+        let k = Key { t: Duration::from_millis(ms) };
+        let s = serde_json::to_string(&k).unwrap();
+        assert_eq!(serde_json::from_str::<Key>(&s).unwrap(), k);
+    }
+    let k: Key = serde_json::from_str(r#"{"t":"065000"}"#).unwrap();
+    assert_eq!(k.t, Duration::from_secs(65));
+    let k: Key = serde_json::from_str(r#"{"t":65000}"#).unwrap();
+    assert_eq!(k.t, Duration::from_secs(65));
+}
+
+#[test]
+fn strings_sort_in_time_order() {
+    let mut ds: Vec<Duration> = [90_000, 5, 1_000_000, 65_000, 0, 999].map(Duration::from_millis).to_vec();
+    let mut keys: Vec<String> = ds.iter().map(|d| to_padded_string(d, 11).unwrap()).collect();
+    keys.sort();
+    ds.sort();
+    assert_eq!(keys, ds.iter().map(|d| to_padded_string(d, 11).unwrap()).collect::<Vec<_>>());
+}
+
+#[test]
+fn too_wide_is_an_error() {
+    let err = to_padded_string(&Duration::from_millis(100_000_000_000), 11).unwrap_err();
+    assert_eq!(err, SerializeDurationError::TooWide { digits: 12, width: 11 });
+    assert_eq!(err.to_string(), "duration needs 12 digits, more than the width of 11");
+    assert_eq!(serde_json::to_string(&Short { t: Duration::from_secs(999) }).unwrap(), r#"{"t":"999000"}"#);
+    assert!(serde_json::to_string(&Short { t: Duration::from_secs(1000) }).is_err());
+}
+
+#[test]
+fn rejects_non_digits() {
+    assert!(serde_json::from_str::<Key>(r#"{"t":"1m"}"#).is_err());
+    assert!(serde_json::from_str::<Key>(r#"{"t":"-0001"}"#).is_err());
+    assert!(serde_json::from_str::<Key>(r#"{"t":1.5}"#).is_err());
+}
@@ -0,0 +1,46 @@
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+use std::time::Duration;
+
+thread_local! {
+    static ALLOCS: Cell<usize> = const { Cell::new(0) };
+}
+
+struct Counting;
+
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCS.with(|c| c.set(c.get() + 1));
+        System.alloc(layout)
+    }
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: Counting = Counting;
+
+fn allocations<T>(f: impl FnOnce() -> T) -> (T, usize) {
+    let before = ALLOCS.with(Cell::get);
+    let out = f();
+    (out, ALLOCS.with(Cell::get) - before)
+}
+
+#[test]
+fn parse_str_does_not_allocate() {
+    use serde_ext_duration::{parse_str, parse_str_localized, Locale};
+    let (d, n) = allocations(|| parse_str("1D 2H 3M 4S 5MS 6us 7NS"));
+    assert_eq!(d.unwrap(), Duration::new(93_784, 5_006_007));
+    assert_eq!(n, 0);
+    let (d, n) = allocations(|| parse_str("1.5 Hours 30 MINUTES 250ms"));
+    assert_eq!(d.unwrap(), Duration::from_millis(7_200_250));
+    assert_eq!(n, 0);
+    let (d, n) = allocations(|| parse_str_localized("1 Stunde 30 Minuten", Locale::German));
+    assert_eq!(d.unwrap(), Duration::from_secs(5400));
+    assert_eq!(n, 0);
+    // only the error path builds a message
+    let (e, n) = allocations(|| parse_str("5 Parsecs"));
+    assert_eq!(e.unwrap_err(), "unknown unit 'parsecs' (e.g. d, h, m, s, ms, hours, µs; see `units()`)");
+    assert!(n > 0);
+}
@@ -119,3 +119,36 @@ fn opt_skip_serializing_if_none() {
     let j = serde_json::to_string(&v).unwrap();
     assert_eq!(j, "{}");
 }
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct OptNanos {
+    #[serde(default, with = "serde_ext_duration::opt::nanos_in")]
+    timeout: Option<Duration>,
+}
+
+#[test]
+fn opt_nanos_in_round_trip() {
+    let v: OptNanos = serde_json::from_str(r#"{"timeout": 1500}"#).unwrap();
+    assert_eq!(v.timeout, Some(Duration::from_nanos(1500)));
+    assert_eq!(serde_json::to_string(&v).unwrap(), r#"{"timeout":1500}"#);
+
+    let v: OptNanos = serde_json::from_str(r#"{}"#).unwrap();
+    assert_eq!(v.timeout, None);
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct OptCentis {
+    #[serde(default, with = "serde_ext_duration::opt::centis")]
+    timeout: Option<Duration>,
+}
+
+#[test]
+fn opt_centis_round_trip() {
+    let v: OptCentis = serde_json::from_str(r#"{"timeout": 150}"#).unwrap();
+    assert_eq!(v.timeout, Some(Duration::from_millis(1500)));
+    assert_eq!(serde_json::to_string(&v).unwrap(), r#"{"timeout":150}"#);
+
+    let v: OptCentis = serde_json::from_str(r#"{"timeout": null}"#).unwrap();
+    assert_eq!(v.timeout, None);
+    assert_eq!(serde_json::to_string(&v).unwrap(), r#"{"timeout":null}"#);
+}
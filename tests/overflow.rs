@@ -0,0 +1,88 @@
+use serde::de::{value::Error, IntoDeserializer};
+use serde::Serialize;
+use serde_ext_duration::{deserialize_with_policy, parse_str_with, OverflowPolicy, ParseOptions};
+use std::time::Duration;
+
+const MAX_NANOS: u128 = u64::MAX as u128 * 1_000_000_000 + 999_999_999;
+
+fn opts(policy: OverflowPolicy) -> ParseOptions {
+    ParseOptions::new().overflow(policy)
+}
+
+#[test]
+fn parser_policies() {
+    let big = format!("{}s 1s", u64::MAX);
+    assert_eq!(parse_str_with(&big, &ParseOptions::new()).unwrap_err(), "duration too large");
+    assert_eq!(parse_str_with(&big, &opts(OverflowPolicy::Saturate)).unwrap(), Duration::MAX);
+    // u64::MAX s + 1s is exactly one full turn
+    assert_eq!(parse_str_with(&big, &opts(OverflowPolicy::Wrap)).unwrap(), Duration::ZERO);
+    let past = format!("{}s 3s", u64::MAX);
+    assert_eq!(parse_str_with(&past, &opts(OverflowPolicy::Wrap)).unwrap(), Duration::from_secs(2));
+
+    let huge = "1".repeat(50) + "d";
+    assert!(parse_str_with(&huge, &ParseOptions::new()).unwrap_err().contains("invalid number"));
+    assert_eq!(parse_str_with(&huge, &opts(OverflowPolicy::Saturate)).unwrap(), Duration::MAX);
+    // ((MAX_NANOS + 1) * 1000 + 5) ms wraps to 5ms
+    let k_times = format!("{}ms", (MAX_NANOS + 1) * 1000 + 5);
+    assert_eq!(parse_str_with(&k_times, &opts(OverflowPolicy::Wrap)).unwrap(), Duration::from_millis(5));
+    assert!(parse_str_with(&huge, &opts(OverflowPolicy::Wrap)).is_ok());
+
+    // syntax errors still win under every policy
+    assert!(parse_str_with("5x", &opts(OverflowPolicy::Saturate)).is_err());
+    assert_eq!(parse_str_with("1h", &opts(OverflowPolicy::Wrap)).unwrap(), Duration::from_secs(3600));
+}
+
+#[test]
+fn deserialize_policies() {
+    let de = |v: f64, p| deserialize_with_policy(IntoDeserializer::<Error>::into_deserializer(v), p);
+    assert!(de(1e30, OverflowPolicy::Error).is_err());
+    assert_eq!(de(1e30, OverflowPolicy::Saturate).unwrap(), Duration::MAX);
+    assert!(de(1e30, OverflowPolicy::Wrap).is_ok());
+    assert_eq!(de(1.5, OverflowPolicy::Wrap).unwrap(), Duration::from_millis(1500));
+
+    let de_str = |v: &str, p| deserialize_with_policy(IntoDeserializer::<Error>::into_deserializer(v), p);
+    let digits = "99999999999999999999999";
+    assert!(de_str(digits, OverflowPolicy::Error).unwrap_err().to_string().contains("overflow"));
+    assert_eq!(de_str(digits, OverflowPolicy::Saturate).unwrap(), Duration::MAX);
+    let big = format!("{}s 1s", u64::MAX);
+    assert_eq!(de_str(&big, OverflowPolicy::Saturate).unwrap(), Duration::MAX);
+    assert_eq!(de_str("90", OverflowPolicy::Saturate).unwrap(), Duration::from_secs(90));
+}
+
+mod millis_sat {
+    use serde::Serializer;
+    use serde_ext_duration::OverflowPolicy;
+    use std::time::Duration;
+    pub fn serialize<S: Serializer>(d: &Duration, s: S) -> Result<S::Ok, S::Error> {
+        serde_ext_duration::serialize_millis_with_policy(d, OverflowPolicy::Saturate, s)
+    }
+}
+
+#[derive(Serialize)]
+struct Out {
+    #[serde(serialize_with = "millis_sat::serialize")]
+    t: Duration,
+}
+
+#[test]
+fn serializer_policies() {
+    use serde_ext_duration::{serialize_nanos_with_policy, serialize_secs_f64_ms_with_policy};
+    assert_eq!(serde_json::to_string(&Out { t: Duration::MAX }).unwrap(), format!(r#"{{"t":{}}}"#, u64::MAX));
+    assert_eq!(serde_json::to_string(&Out { t: Duration::from_millis(5) }).unwrap(), r#"{"t":5}"#);
+
+    let nanos = |d, p| serialize_nanos_with_policy(&d, p, serde_json::value::Serializer).map_err(|e| e.to_string());
+    let year_600 = Duration::from_secs(600 * 365 * 86_400);
+    assert_eq!(nanos(year_600, OverflowPolicy::Error).unwrap_err(), "duration too large");
+    assert_eq!(nanos(year_600, OverflowPolicy::Saturate).unwrap(), serde_json::json!(u64::MAX));
+    assert_eq!(nanos(year_600, OverflowPolicy::Wrap).unwrap(), serde_json::json!(year_600.as_nanos() as u64));
+
+    let f64ms =
+        |d, p| serialize_secs_f64_ms_with_policy(&d, p, serde_json::value::Serializer).map_err(|e| e.to_string());
+    assert_eq!(f64ms(Duration::from_millis(1500), OverflowPolicy::Error).unwrap(), serde_json::json!(1.5));
+    let past = Duration::from_millis((1u64 << 53) + 1);
+    assert_eq!(f64ms(past, OverflowPolicy::Error).unwrap_err(), "duration too large");
+    assert_eq!(f64ms(past, OverflowPolicy::Saturate).unwrap(), serde_json::json!((1u64 << 53) as f64 / 1000.0));
+    assert_eq!(f64ms(past, OverflowPolicy::Wrap).unwrap(), serde_json::json!(0.001));
+    let exact = Duration::from_millis(1u64 << 53);
+    assert_eq!(f64ms(exact, OverflowPolicy::Wrap).unwrap(), serde_json::json!((1u64 << 53) as f64 / 1000.0));
+}
@@ -0,0 +1,210 @@
+use serde::Deserialize;
+use serde_ext_duration::{parse_str, parse_str_with, NumberFormat, ParseOptions};
+use std::time::Duration;
+
+#[derive(Deserialize)]
+struct Comma {
+    #[serde(with = "serde_ext_duration::decimal_comma")]
+    t: Duration,
+}
+
+#[test]
+fn decimal_comma_fractions() {
+    let opts = ParseOptions::new().number_format(NumberFormat::DecimalComma);
+    assert_eq!(parse_str_with("1,5s", &opts).unwrap(), Duration::from_millis(1500));
+    assert_eq!(parse_str_with("0,25h 1m", &opts).unwrap(), Duration::from_secs(16 * 60));
+    assert_eq!(parse_str_with("2 m", &opts).unwrap(), Duration::from_secs(120));
+
+    let v: Comma = serde_json::from_str(r#"{ "t": "1,5s" }"#).unwrap();
+    assert_eq!(v.t, Duration::from_millis(1500));
+}
+
+#[test]
+fn decimal_comma_is_opt_in() {
+    assert!(parse_str("1,5s").is_err());
+    assert_eq!(parse_str_with("1m", &ParseOptions::default()).unwrap(), Duration::from_secs(60));
+
+    // a comma needs digits on both sides
+    let opts = ParseOptions::new().number_format(NumberFormat::DecimalComma);
+    assert!(parse_str_with("1,s", &opts).is_err());
+    assert!(parse_str_with(",5s", &opts).is_err());
+}
+
+#[derive(Deserialize)]
+struct Dot {
+    #[serde(with = "serde_ext_duration::thousands_dot")]
+    t: Duration,
+}
+
+#[test]
+fn thousands_dot_groups_digits() {
+    let opts = ParseOptions::new().number_format(NumberFormat::ThousandsDot);
+    assert_eq!(parse_str_with("1.000s", &opts).unwrap(), Duration::from_secs(1000));
+    assert_eq!(parse_str_with("1.000.000ms", &opts).unwrap(), Duration::from_secs(1000));
+    assert_eq!(parse_str_with("2h 1.500s", &opts).unwrap(), Duration::from_secs(7200 + 1500));
+    assert_eq!(parse_str_with("15s", &opts).unwrap(), Duration::from_secs(15));
+
+    let v: Dot = serde_json::from_str(r#"{ "t": "1.000s" }"#).unwrap();
+    assert_eq!(v.t, Duration::from_secs(1000));
+    // the default grammar still reads `.` as a decimal point
+    assert_eq!(parse_str("1.000s").unwrap(), Duration::from_secs(1));
+}
+
+#[test]
+fn thousands_dot_has_no_decimal_point() {
+    let opts = ParseOptions::new().number_format(NumberFormat::ThousandsDot);
+    let err = parse_str_with("1.5s", &opts).unwrap_err();
+    assert_eq!(err, "digit group at position 2 must have 3 digits");
+    assert!(parse_str_with("1.0000s", &opts).is_err());
+    assert!(parse_str_with("1.s", &opts).is_err());
+    assert!(parse_str_with(".000s", &opts).is_err());
+}
+
+#[derive(Deserialize)]
+struct CommaGroups {
+    #[serde(with = "serde_ext_duration::thousands_comma")]
+    t: Duration,
+}
+
+#[test]
+fn thousands_comma_groups_digits() {
+    let opts = ParseOptions::new().number_format(NumberFormat::ThousandsComma);
+    assert_eq!(parse_str_with("1,250ms", &opts).unwrap(), Duration::from_millis(1250));
+    assert_eq!(parse_str_with("1,000,000ms", &opts).unwrap(), Duration::from_secs(1000));
+    assert_eq!(parse_str_with("1,000.5s", &opts).unwrap(), Duration::from_millis(1_000_500));
+    assert_eq!(parse_str_with("1h 2,500ms", &opts).unwrap(), Duration::from_millis(3_602_500));
+
+    let v: CommaGroups = serde_json::from_str(r#"{ "t": "1,250ms" }"#).unwrap();
+    assert_eq!(v.t, Duration::from_millis(1250));
+    // groups need three digits, and the default grammar has no grouping at all
+    let err = parse_str_with("1,25ms", &opts).unwrap_err();
+    assert_eq!(err, "digit group at position 2 must have 3 digits");
+    assert!(parse_str_with("1,250ms", &ParseOptions::default()).is_err());
+}
+
+#[test]
+fn localized_unit_words() {
+    use serde_ext_duration::{parse_str_localized, Locale};
+    let ninety_min = Duration::from_secs(90 * 60);
+    assert_eq!(parse_str_localized("1 hour 30 minutes", Locale::English).unwrap(), ninety_min);
+    assert_eq!(parse_str_localized("1 hora 30 minutos", Locale::Spanish).unwrap(), ninety_min);
+    assert_eq!(parse_str_localized("1 heure 30 minutes", Locale::French).unwrap(), ninety_min);
+    assert_eq!(parse_str_localized("1 Stunde 30 Minuten", Locale::German).unwrap(), ninety_min);
+    assert_eq!(parse_str_localized("2 días 1 segundo", Locale::Spanish).unwrap(), Duration::from_secs(2 * 86_400 + 1));
+    assert_eq!(
+        parse_str_localized("2 Tage 500 Millisekunden", Locale::German).unwrap(),
+        Duration::from_millis(172_800_500)
+    );
+
+    // abbreviations always work; other languages' words do not
+    assert_eq!(parse_str_localized("1h 30m", Locale::French).unwrap(), ninety_min);
+    assert!(parse_str_localized("1 Stunde", Locale::French).unwrap_err().contains("unknown unit 'stunde'"));
+    // English words are part of the default grammar (humantime compatibility), others are not
+    assert_eq!(parse_str("1 hour").unwrap(), Duration::from_secs(3600));
+    assert!(parse_str("1 Stunde").is_err());
+}
+
+#[test]
+fn leading_zeros_rejected_only_when_strict() {
+    assert_eq!(parse_str("007s").unwrap(), Duration::from_secs(7));
+    let strict = ParseOptions::new().reject_leading_zeros(true);
+    assert_eq!(parse_str_with("0s 10m", &strict).unwrap(), Duration::from_secs(600));
+    assert_eq!(parse_str_with("1m 0s", &strict).unwrap(), Duration::from_secs(60));
+    assert_eq!(parse_str_with("007s", &strict).unwrap_err(), "leading zero in number at position 0");
+    assert_eq!(parse_str_with("1h 05m", &strict).unwrap_err(), "leading zero in number at position 3");
+    let comma = strict.number_format(NumberFormat::DecimalComma);
+    assert_eq!(parse_str_with("0,5s", &comma).unwrap(), Duration::from_millis(500));
+}
+
+#[test]
+fn plural_s_only_after_h_and_d() {
+    let plural = ParseOptions::new().plural_units(true);
+    assert_eq!(parse_str_with("3hs", &plural).unwrap(), Duration::from_secs(3 * 3600));
+    assert_eq!(parse_str_with("2ds 1HS", &plural).unwrap(), Duration::from_secs(2 * 86_400 + 3600));
+    // collisions keep their strict meaning
+    assert_eq!(parse_str_with("5ms", &plural).unwrap(), Duration::from_millis(5));
+    assert_eq!(parse_str_with("5m", &plural).unwrap(), Duration::from_secs(300));
+    assert!(parse_str_with("5ss", &plural).unwrap_err().contains("unknown unit 'ss'"));
+    assert!(parse_str_with("5mss", &plural).is_err());
+    assert!(parse_str_with("5hss", &plural).is_err());
+    // off by default
+    assert!(parse_str("3hs").unwrap_err().contains("unknown unit 'hs'"));
+}
+
+#[test]
+fn long_strings_rejected_before_scanning() {
+    use serde_ext_duration::DEFAULT_MAX_LEN;
+    let long = "1s ".repeat(100);
+    assert_eq!(parse_str(&long).unwrap_err(), format!("duration string too long (300 bytes, limit {DEFAULT_MAX_LEN})"));
+    assert_eq!(parse_str(&"1s ".repeat(85)).unwrap(), Duration::from_secs(85));
+    let roomy = ParseOptions::new().max_len(1024);
+    assert_eq!(parse_str_with(&long, &roomy).unwrap(), Duration::from_secs(100));
+    assert!(parse_str_with("1h", &ParseOptions::new().max_len(1)).unwrap_err().contains("too long"));
+
+    #[derive(Deserialize, Debug)]
+    struct Cfg {
+        #[serde(with = "serde_ext_duration")]
+        #[allow(dead_code)]
+        t: Duration,
+    }
+    let err = serde_json::from_str::<Cfg>(&format!(r#"{{"t":"{}"}}"#, "x".repeat(10_000))).unwrap_err();
+    assert!(err.to_string().contains("too long"));
+}
+
+#[test]
+fn parse_str_nanos_is_not_capped() {
+    use serde_ext_duration::parse_str_nanos;
+    assert_eq!(parse_str_nanos("0").unwrap(), 0);
+    assert_eq!(parse_str_nanos("1h 250ms").unwrap(), 3_600_250_000_000);
+    assert_eq!(parse_str_nanos("1.5us").unwrap(), 1_500);
+    let max = Duration::MAX.as_nanos();
+    assert_eq!(parse_str_nanos("18446744073709551615s 999999999ns").unwrap(), max);
+    assert_eq!(parse_str_nanos("18446744073709551615s 1000000000ns").unwrap(), max + 1);
+    assert_eq!(parse_str_nanos("1000000000000000000000000d").unwrap(), 86_400 * 10u128.pow(33));
+    assert_eq!(parse_str("18446744073709551615s 1000000000ns").unwrap_err(), "duration too large");
+
+    let err = parse_str_nanos("10000000000000000000000000000000000000d").unwrap_err();
+    assert_eq!(err.message(), "duration overflow");
+    let err = parse_str_nanos("5x").unwrap_err();
+    assert_eq!(
+        (err.input(), err.message()),
+        ("5x", "unknown unit 'x' (e.g. d, h, m, s, ms, hours, µs; see `units()`)")
+    );
+}
+
+#[test]
+fn one_of_allowed_values() {
+    use serde_ext_duration::one_of;
+    let allowed = [Duration::from_secs(60), Duration::from_secs(300), Duration::from_secs(900)];
+    let parse = one_of(&allowed);
+    assert_eq!(parse("5m").unwrap(), Duration::from_secs(300));
+    assert_eq!(parse("300s").unwrap(), Duration::from_secs(300));
+    assert_eq!(parse("2m").unwrap_err(), "2m is not allowed (use one of 1m, 5m, 15m)");
+    assert_eq!(parse("5x").unwrap_err(), "unknown unit 'x' (e.g. d, h, m, s, ms, hours, µs; see `units()`)");
+    assert!(one_of(&[])("1m").is_err());
+}
+
+mod poll_interval {
+    use serde::Deserializer;
+    use std::time::Duration;
+    const ALLOWED: &[Duration] = &[Duration::from_secs(60), Duration::from_secs(300), Duration::from_secs(900)];
+    pub fn deserialize<'de, D: Deserializer<'de>>(d: D) -> Result<Duration, D::Error> {
+        serde_ext_duration::deserialize_one_of(d, ALLOWED)
+    }
+}
+
+#[derive(Deserialize, Debug)]
+struct Poller {
+    #[serde(deserialize_with = "poll_interval::deserialize")]
+    every: Duration,
+}
+
+#[test]
+fn deserialize_one_of_checks_every_input_form() {
+    let p: Poller = serde_json::from_str(r#"{"every":"15m"}"#).unwrap();
+    assert_eq!(p.every, Duration::from_secs(900));
+    let p: Poller = serde_json::from_str(r#"{"every":60}"#).unwrap();
+    assert_eq!(p.every, Duration::from_secs(60));
+    let err = serde_json::from_str::<Poller>(r#"{"every":61}"#).unwrap_err().to_string();
+    assert!(err.contains("1m 1s is not allowed (use one of 1m, 5m, 15m)"), "{err}");
+}
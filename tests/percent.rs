@@ -0,0 +1,30 @@
+use serde_ext_duration::percent::{percent_of, percent_of_period};
+use std::time::Duration;
+
+#[test]
+fn duty_cycle_of_period() {
+    let period = Duration::from_secs(2);
+    assert_eq!(percent_of_period("50%", period).unwrap(), Duration::from_secs(1));
+    assert_eq!(percent_of_period(" 12.5 % ", period).unwrap(), Duration::from_millis(250));
+    assert_eq!(percent_of_period("100%", period).unwrap(), period);
+    assert_eq!(percent_of_period("0%", period).unwrap(), Duration::ZERO);
+    assert_eq!(percent_of_period("33.333333333%", Duration::from_nanos(3)).unwrap(), Duration::from_nanos(1));
+    assert_eq!(percent_of_period("150%", period).unwrap_err(), "duty cycle '150%' exceeds 100%");
+}
+
+#[test]
+fn percent_of_any_base() {
+    assert_eq!(percent_of("150%", Duration::from_secs(10)).unwrap(), Duration::from_secs(15));
+    assert_eq!(percent_of("100%", Duration::MAX).unwrap(), Duration::MAX);
+    assert_eq!(percent_of("200%", Duration::MAX).unwrap_err(), "duration too large");
+    let huge = format!("{}%", u128::MAX / 10);
+    assert_eq!(percent_of(&huge, Duration::from_nanos(99)).unwrap_err(), "duration overflow");
+}
+
+#[test]
+fn rejects_malformed_percentages() {
+    let base = Duration::from_secs(1);
+    for bad in ["50", "%", "-5%", "1.%", ".5%", "5.5.5%", "five%"] {
+        assert!(percent_of(bad, base).is_err(), "{bad}");
+    }
+}
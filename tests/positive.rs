@@ -0,0 +1,44 @@
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+#[derive(Serialize, Deserialize, Debug)]
+struct Cfg {
+    #[serde(with = "serde_ext_duration::positive")]
+    timeout: Duration,
+}
+
+#[derive(Deserialize, Debug)]
+struct OptCfg {
+    #[serde(default, with = "serde_ext_duration::opt::positive")]
+    timeout: Option<Duration>,
+}
+
+#[test]
+fn accepts_non_zero_in_any_form() {
+    for src in [r#"{"timeout":"30s"}"#, r#"{"timeout":30}"#, r#"{"timeout":0.001}"#, r#"{"timeout":"1ms"}"#] {
+        let v: Cfg = serde_json::from_str(src).unwrap();
+        assert!(v.timeout > Duration::ZERO, "{src}");
+    }
+    let json = serde_json::to_string(&Cfg { timeout: Duration::from_secs(90) }).unwrap();
+    assert_eq!(json, r#"{"timeout":"1m 30s"}"#);
+}
+
+#[test]
+fn rejects_zero_in_any_form() {
+    for src in [r#"{"timeout":0}"#, r#"{"timeout":"0"}"#, r#"{"timeout":"0s"}"#, r#"{"timeout":0.0}"#] {
+        let err = serde_json::from_str::<Cfg>(src).unwrap_err().to_string();
+        assert!(err.contains("duration must be greater than zero"), "{src}: {err}");
+    }
+}
+
+#[test]
+fn opt_allows_none_but_not_zero() {
+    let v: OptCfg = serde_json::from_str("{}").unwrap();
+    assert_eq!(v.timeout, None);
+    let v: OptCfg = serde_json::from_str(r#"{"timeout":null}"#).unwrap();
+    assert_eq!(v.timeout, None);
+    let v: OptCfg = serde_json::from_str(r#"{"timeout":"5s"}"#).unwrap();
+    assert_eq!(v.timeout, Some(Duration::from_secs(5)));
+    let err = serde_json::from_str::<OptCfg>(r#"{"timeout":"0s"}"#).unwrap_err();
+    assert!(err.to_string().contains("duration must be greater than zero"));
+}
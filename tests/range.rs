@@ -0,0 +1,35 @@
+use serde::{Deserialize, Serialize};
+use std::ops::Range;
+use std::time::Duration;
+
+#[derive(Serialize, Deserialize, Debug)]
+struct Window {
+    #[serde(with = "serde_ext_duration::range")]
+    w: Range<Duration>,
+}
+
+fn h(n: u64) -> Duration {
+    Duration::from_secs(n * 3600)
+}
+
+#[test]
+fn range_round_trip() {
+    let j = serde_json::to_string(&Window { w: h(1)..h(2) }).unwrap();
+    assert_eq!(j, r#"{"w":{"start":"1h","end":"2h"}}"#);
+    assert_eq!(serde_json::from_str::<Window>(&j).unwrap().w, h(1)..h(2));
+    let v: Window = serde_json::from_str(r#"{"w":{"end":7200,"start":"30m"}}"#).unwrap();
+    assert_eq!(v.w, Duration::from_secs(1800)..h(2));
+    let v: Window = serde_yaml::from_str("w:\n  start: 1h\n  end: 1h").unwrap();
+    assert!(v.w.is_empty());
+}
+
+#[test]
+fn rejects_inverted_and_incomplete_ranges() {
+    let err = serde_json::from_str::<Window>(r#"{"w":{"start":"2h","end":"1h"}}"#).unwrap_err();
+    assert!(err.to_string().contains("inverted range: start 2h is after end 1h"));
+    let err = serde_json::from_str::<Window>(r#"{"w":{"start":"1h"}}"#).unwrap_err();
+    assert!(err.to_string().contains("missing field `end`"));
+    let err = serde_json::from_str::<Window>(r#"{"w":{"start":"1h","end":"2h","step":"1m"}}"#).unwrap_err();
+    assert!(err.to_string().contains("unknown field `step`"));
+    assert!(serde_json::from_str::<Window>(r#"{"w":["1h","2h"]}"#).is_err());
+}
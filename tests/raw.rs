@@ -0,0 +1,35 @@
+use serde::{Deserialize, Serialize};
+use serde_ext_duration::RawDuration;
+use std::time::Duration;
+
+#[derive(Serialize, Deserialize, Debug)]
+struct Cfg {
+    timeout: RawDuration,
+}
+
+#[test]
+fn original_spelling_round_trips() {
+    for src in [r#"{"timeout":"90m"}"#, r#"{"timeout":"30m 1h"}"#, r#"{"timeout":" 1.5"}"#] {
+        let c: Cfg = serde_json::from_str(src).unwrap();
+        assert_eq!(serde_json::to_string(&c).unwrap(), src);
+    }
+    let c: Cfg = serde_json::from_str(r#"{"timeout":"90m"}"#).unwrap();
+    assert_eq!(c.timeout.value, Duration::from_secs(5400));
+    assert_eq!(c.timeout.original, "90m");
+}
+
+#[test]
+fn changed_value_wins_over_original() {
+    let mut c: Cfg = serde_json::from_str(r#"{"timeout":"90m"}"#).unwrap();
+    c.timeout.value = Duration::from_secs(60);
+    assert_eq!(serde_json::to_string(&c).unwrap(), r#"{"timeout":"1m"}"#);
+    let fresh = Cfg { timeout: RawDuration::from(Duration::from_millis(1500)) };
+    assert_eq!(serde_json::to_string(&fresh).unwrap(), r#"{"timeout":"1s 500ms"}"#);
+}
+
+#[test]
+fn rejects_numbers_and_bad_strings() {
+    assert!(serde_json::from_str::<Cfg>(r#"{"timeout":90}"#).is_err());
+    let err = serde_json::from_str::<Cfg>(r#"{"timeout":"5x"}"#).unwrap_err();
+    assert!(err.to_string().contains("unknown unit 'x'"));
+}
@@ -0,0 +1,59 @@
+use serde::{Deserialize, Serialize};
+use serde_ext_duration::relative::parse;
+use std::time::Duration;
+
+#[derive(Serialize, Deserialize, Debug)]
+struct Job {
+    #[serde(with = "serde_ext_duration::relative")]
+    delay: Duration,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+struct NowJob {
+    #[serde(with = "serde_ext_duration::relative::now")]
+    delay: Duration,
+}
+
+#[derive(Deserialize, Debug)]
+struct OptJob {
+    #[serde(default, with = "serde_ext_duration::opt::relative")]
+    delay: Option<Duration>,
+}
+
+#[test]
+fn keywords_mean_zero() {
+    assert_eq!(parse("now").unwrap(), Duration::ZERO);
+    assert_eq!(parse(" NOW ").unwrap(), Duration::ZERO);
+    assert_eq!(parse("immediately").unwrap(), Duration::ZERO);
+    let v: Job = serde_json::from_str(r#"{"delay":"now"}"#).unwrap();
+    assert_eq!(v.delay, Duration::ZERO);
+}
+
+#[test]
+fn everything_else_is_flexible() {
+    assert_eq!(parse("5m").unwrap(), Duration::from_secs(300));
+    let v: Job = serde_json::from_str(r#"{"delay":90}"#).unwrap();
+    assert_eq!(v.delay, Duration::from_secs(90));
+    assert!(parse("nowish").is_err());
+    assert!(parse("now 5m").is_err());
+}
+
+#[test]
+fn zero_serializes_as_0s_or_now() {
+    let json = serde_json::to_string(&Job { delay: Duration::ZERO }).unwrap();
+    assert_eq!(json, r#"{"delay":"0s"}"#);
+    let json = serde_json::to_string(&NowJob { delay: Duration::ZERO }).unwrap();
+    assert_eq!(json, r#"{"delay":"now"}"#);
+    let json = serde_json::to_string(&NowJob { delay: Duration::from_secs(5) }).unwrap();
+    assert_eq!(json, r#"{"delay":"5s"}"#);
+    let back: NowJob = serde_json::from_str(r#"{"delay":"now"}"#).unwrap();
+    assert_eq!(back.delay, Duration::ZERO);
+}
+
+#[test]
+fn opt_relative() {
+    let v: OptJob = serde_json::from_str(r#"{"delay":"immediately"}"#).unwrap();
+    assert_eq!(v.delay, Some(Duration::ZERO));
+    let v: OptJob = serde_json::from_str("{}").unwrap();
+    assert_eq!(v.delay, None);
+}
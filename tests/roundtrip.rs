@@ -0,0 +1,91 @@
+use proptest::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+// One wrapper per serializer format; `round_trip` pushes a duration through JSON and back.
+macro_rules! format_wrapper {
+    ($name:ident, $with:literal) => {
+        #[derive(Serialize, Deserialize)]
+        struct $name {
+            #[serde(with = $with)]
+            t: Duration,
+        }
+
+        #[allow(dead_code)]
+        impl $name {
+            fn round_trip(d: Duration) -> Duration {
+                let json = serde_json::to_string(&$name { t: d }).unwrap();
+                serde_json::from_str::<$name>(&json).unwrap_or_else(|e| panic!("{json}: {e}")).t
+            }
+        }
+    };
+}
+
+format_wrapper!(Human, "serde_ext_duration::human");
+format_wrapper!(Secs, "serde_ext_duration::secs");
+format_wrapper!(Millis, "serde_ext_duration::millis");
+format_wrapper!(SecsF64Ms, "serde_ext_duration::secs_f64_ms");
+format_wrapper!(Nanos, "serde_ext_duration::nanos_in");
+format_wrapper!(Centis, "serde_ext_duration::centis");
+format_wrapper!(Iso8601, "serde_ext_duration::iso8601");
+
+/// Any duration whose whole seconds stay below `max_secs`.
+fn duration_below(max_secs: u64) -> impl Strategy<Value = Duration> {
+    (0..max_secs, 0..1_000_000_000u32).prop_map(|(s, n)| Duration::new(s, n))
+}
+
+/// `d` rounded to a multiple of `step` nanoseconds, half-up.
+fn round_to(d: Duration, step: u128) -> Duration {
+    nanos_to_duration((d.as_nanos() + step / 2) / step * step)
+}
+
+/// `d` truncated to a multiple of `step` nanoseconds.
+fn floor_to(d: Duration, step: u128) -> Duration {
+    nanos_to_duration(d.as_nanos() / step * step)
+}
+
+fn nanos_to_duration(n: u128) -> Duration {
+    Duration::new((n / 1_000_000_000) as u64, (n % 1_000_000_000) as u32)
+}
+
+proptest! {
+    #[test]
+    fn human_rounds_to_the_millisecond(d in duration_below(u64::MAX)) {
+        prop_assert_eq!(Human::round_trip(d), round_to(d, 1_000_000));
+    }
+
+    #[test]
+    fn secs_truncates_to_the_second(d in duration_below(u64::MAX)) {
+        prop_assert_eq!(Secs::round_trip(d), floor_to(d, 1_000_000_000));
+    }
+
+    // `millis` reads bare integers back as seconds, like every flexible input, so its output is
+    // checked as the millisecond count it documents rather than through its own `deserialize`.
+    #[test]
+    fn millis_rounds_to_the_millisecond(d in duration_below(u64::MAX / 1000)) {
+        let ms: u64 = serde_json::from_value(serde_json::to_value(Millis { t: d }).unwrap()["t"].take()).unwrap();
+        prop_assert_eq!(Duration::from_millis(ms), round_to(d, 1_000_000));
+    }
+
+    #[test]
+    fn secs_f64_ms_stays_within_a_millisecond(d in duration_below(1 << 30)) {
+        let back = SecsF64Ms::round_trip(d);
+        let diff = back.as_nanos().abs_diff(d.as_nanos());
+        prop_assert!(diff < 1_000_000, "{:?} came back as {:?}", d, back);
+    }
+
+    #[test]
+    fn nanos_is_exact(d in duration_below(u64::MAX / 1_000_000_000)) {
+        prop_assert_eq!(Nanos::round_trip(d), d);
+    }
+
+    #[test]
+    fn centis_rounds_to_the_centisecond(d in duration_below(u64::MAX / 100)) {
+        prop_assert_eq!(Centis::round_trip(d), round_to(d, 10_000_000));
+    }
+
+    #[test]
+    fn iso8601_is_exact(d in duration_below(u64::MAX)) {
+        prop_assert_eq!(Iso8601::round_trip(d), d);
+    }
+}
@@ -0,0 +1,29 @@
+use serde::{Deserialize, Serialize};
+use serde_ext_duration::schedule::parse;
+use std::time::Duration;
+
+#[derive(Serialize, Deserialize, Debug)]
+struct Job {
+    #[serde(with = "serde_ext_duration::schedule")]
+    run: Duration,
+}
+
+#[test]
+fn every_prefix_is_stripped() {
+    assert_eq!(parse("every 1h 30m").unwrap(), Duration::from_secs(90 * 60));
+    assert_eq!(parse("  Every\t30m").unwrap(), Duration::from_secs(30 * 60));
+    assert_eq!(parse("30m").unwrap(), Duration::from_secs(30 * 60));
+    assert!(parse("every").unwrap_err().contains("expected number"));
+    assert!(parse("every30m").is_err());
+}
+
+#[test]
+fn schedule_round_trip() {
+    let j = serde_json::to_string(&Job { run: Duration::from_secs(5400) }).unwrap();
+    assert_eq!(j, r#"{"run":"every 1h 30m"}"#);
+    let back: Job = serde_json::from_str(&j).unwrap();
+    assert_eq!(back.run, Duration::from_secs(5400));
+
+    // the core parser stays strict
+    assert!(serde_ext_duration::parse_str("every 1h").is_err());
+}
@@ -0,0 +1,45 @@
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct Step {
+    #[serde(with = "serde_ext_duration::seq_unit_first")]
+    wait: Duration,
+}
+
+fn read(json: &str) -> Result<Duration, String> {
+    serde_json::from_str::<Step>(&format!(r#"{{"wait":{json}}}"#)).map(|s| s.wait).map_err(|e| e.to_string())
+}
+
+#[test]
+fn unit_then_value() {
+    assert_eq!(read(r#"["h", 1]"#).unwrap(), Duration::from_secs(3600));
+    assert_eq!(read(r#"["m", 5]"#).unwrap(), Duration::from_secs(300));
+    assert_eq!(read(r#"["ms", 250]"#).unwrap(), Duration::from_millis(250));
+    assert_eq!(read(r#"["MIN", 1.5]"#).unwrap(), Duration::from_secs(90));
+    assert_eq!(read(r#"["hours", 2]"#).unwrap(), Duration::from_secs(7200));
+}
+
+#[test]
+fn serializes_as_a_pair_that_reads_back() {
+    for (d, json) in [
+        (Duration::from_secs(3600), r#"{"wait":["h",1]}"#),
+        (Duration::from_secs(90), r#"{"wait":["s",90]}"#),
+        (Duration::from_millis(250), r#"{"wait":["ms",250]}"#),
+    ] {
+        let s = serde_json::to_string(&Step { wait: d }).unwrap();
+        assert_eq!(s, json);
+        assert_eq!(serde_json::from_str::<Step>(&s).unwrap().wait, d);
+    }
+}
+
+#[test]
+fn malformed_sequences_are_rejected() {
+    assert!(read(r#"["x", 1]"#).unwrap_err().contains("unknown unit 'x'"));
+    assert!(read(r#"[1, "h"]"#).unwrap_err().contains("invalid type"));
+    assert!(read(r#"["h"]"#).unwrap_err().contains("invalid length 1"));
+    assert!(read(r#"[]"#).unwrap_err().contains("invalid length 0"));
+    assert!(read(r#"["h", 1, 2]"#).unwrap_err().contains("invalid length 3"));
+    assert!(read(r#"["h", -1]"#).is_err());
+    assert!(read(r#""1h""#).is_err());
+}
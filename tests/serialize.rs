@@ -87,3 +87,291 @@ fn root_with_roundtrip() {
     let dst: RootWith = serde_json::from_str(&j).unwrap();
     assert_eq!(dst.t, src.t);
 }
+
+#[test]
+fn millis_too_large_reports_typed_error() {
+    let err = serde_json::to_string(&OutMillis { t: Duration::MAX }).unwrap_err();
+    assert_eq!(err.to_string(), serde_ext_duration::SerializeDurationError::TooLarge.to_string());
+}
+
+#[test]
+fn human_duration_max_round_trips_to_ms() {
+    let j = serde_json::to_string(&RootWith { t: Duration::MAX }).unwrap();
+    assert!(j.contains("213503982334601d 7h 15s 999ms"));
+    let back: RootWith = serde_json::from_str(&j).unwrap();
+    assert_eq!(back.t, Duration::new(u64::MAX, 999_000_000));
+}
+
+#[derive(Serialize, Deserialize)]
+struct InMinutes {
+    #[serde(with = "serde_ext_duration::in_minutes")]
+    t: Duration,
+}
+
+#[test]
+fn in_unit_forces_single_unit() {
+    let j = serde_json::to_string(&InMinutes { t: Duration::from_secs(90 * 60) }).unwrap();
+    assert!(j.contains("\"90m\""));
+    let j = serde_json::to_string(&InMinutes { t: Duration::from_secs(86_400) }).unwrap();
+    assert!(j.contains("\"1440m\""));
+    let back: InMinutes = serde_json::from_str(&j).unwrap();
+    assert_eq!(back.t, Duration::from_secs(86_400));
+}
+
+#[test]
+fn in_unit_rounds_half_up() {
+    let j = serde_json::to_string(&InMinutes { t: Duration::from_secs(89) }).unwrap();
+    assert!(j.contains("\"1m\""));
+    let j = serde_json::to_string(&InMinutes { t: Duration::from_secs(29) }).unwrap();
+    assert!(j.contains("\"0m\""));
+    let j = serde_json::to_string(&InMinutes { t: Duration::from_secs(30) }).unwrap();
+    assert!(j.contains("\"1m\""));
+}
+
+#[derive(Serialize)]
+struct OutAuto {
+    #[serde(serialize_with = "serde_ext_duration::auto_unit::serialize")]
+    t: Duration,
+}
+
+#[test]
+fn auto_unit_picks_coarsest_even_unit() {
+    use serde_ext_duration::{auto_unit, Unit};
+    assert_eq!(auto_unit(&Duration::from_secs(3600)), (1, Unit::Hours));
+    assert_eq!(auto_unit(&Duration::from_secs(2 * 86_400)), (2, Unit::Days));
+    assert_eq!(auto_unit(&Duration::from_secs(90)), (90, Unit::Seconds));
+    assert_eq!(auto_unit(&Duration::from_secs(120)), (2, Unit::Minutes));
+    assert_eq!(auto_unit(&Duration::from_millis(1500)), (1500, Unit::Millis));
+    assert_eq!(auto_unit(&Duration::ZERO), (0, Unit::Days));
+
+    let j = serde_json::to_string(&OutAuto { t: Duration::from_secs(3600) }).unwrap();
+    assert_eq!(j, r#"{"t":1}"#);
+}
+
+#[derive(Serialize)]
+struct OutFloor {
+    #[serde(with = "serde_ext_duration::millis_floor")]
+    ms: Duration,
+    #[serde(with = "serde_ext_duration::human_floor")]
+    human: Duration,
+}
+
+#[test]
+fn floor_vs_round_at_half_millisecond() {
+    let half = Duration::from_micros(500);
+    assert_eq!(serde_json::to_string(&OutFloor { ms: half, human: half }).unwrap(), r#"{"ms":0,"human":"0s"}"#);
+    assert!(serde_json::to_string(&OutMillis { t: half }).unwrap().contains("\"t\":1"));
+    assert!(serde_json::to_string(&OutHuman { t: half }).unwrap().contains("\"1ms\""));
+
+    let d = Duration::new(1, 999_900_000);
+    assert_eq!(serde_json::to_string(&OutFloor { ms: d, human: d }).unwrap(), r#"{"ms":1999,"human":"1s 999ms"}"#);
+}
+
+#[test]
+fn pad_from_inserts_leading_zero_units() {
+    use serde_ext_duration::{HumanFormat, Unit};
+    let hours = HumanFormat::new().pad_from(Unit::Hours);
+    assert_eq!(hours.format(&Duration::from_secs(300)), "0h 5m");
+    assert_eq!(hours.format(&Duration::from_secs(3600 + 5)), "1h 5s");
+    assert_eq!(hours.format(&Duration::from_secs(86_400 + 7200)), "1d 2h");
+    assert_eq!(hours.format(&Duration::ZERO), "0h");
+    let days = HumanFormat::new().pad_from(Unit::Days);
+    assert_eq!(days.format(&Duration::from_millis(250)), "0d 0h 0m 0s 250ms");
+    assert_eq!(HumanFormat::new().format(&Duration::from_secs(300)), "5m");
+}
+
+#[test]
+fn sub_second_as_decimal_seconds() {
+    use serde_ext_duration::HumanFormat;
+    let dec = HumanFormat::new().sub_second_as_decimal_seconds(true);
+    assert_eq!(dec.format(&Duration::from_millis(250)), "0.25s");
+    assert_eq!(dec.format(&Duration::from_millis(5)), "0.005s");
+    assert_eq!(dec.format(&Duration::from_millis(100)), "0.1s");
+    assert_eq!(dec.format(&Duration::ZERO), "0s");
+    assert_eq!(dec.format(&Duration::from_millis(1250)), "1s 250ms");
+    assert_eq!(HumanFormat::new().format(&Duration::from_millis(250)), "250ms");
+}
+
+#[test]
+fn significant_units_round_the_last_kept_unit() {
+    use serde_ext_duration::HumanFormat;
+    let secs = |n| Duration::from_secs(n);
+    let one = HumanFormat::new().significant_units(1);
+    let two = HumanFormat::new().significant_units(2);
+    assert_eq!(one.format(&secs(3600 + 3 * 60 + 17)), "1h");
+    assert_eq!(two.format(&secs(3600 + 3 * 60 + 17)), "1h 3m");
+    assert_eq!(one.format(&secs(3600 + 35 * 60)), "2h");
+    assert_eq!(two.format(&secs(3600 + 3 * 60 + 30)), "1h 4m");
+    assert_eq!(one.format(&secs(59 * 60 + 40)), "1h");
+    assert_eq!(two.format(&secs(86_400 + 5)), "1d");
+    assert_eq!(one.format(&Duration::from_millis(1499)), "1s");
+    assert_eq!(one.format(&Duration::from_millis(250)), "250ms");
+    assert_eq!(one.format(&Duration::ZERO), "0s");
+    assert_eq!(HumanFormat::new().significant_units(9).format(&Duration::from_millis(3_723_250)), "1h 2m 3s 250ms");
+    let all = HumanFormat::new().significant_units(usize::MAX);
+    assert_eq!(all.format(&Duration::from_millis(3_723_250)), "1h 2m 3s 250ms");
+    // rounding never passes what `human` can represent
+    assert!(serde_ext_duration::parse_str(&one.format(&Duration::MAX)).is_ok());
+}
+
+#[test]
+fn human_format_separators() {
+    use serde_ext_duration::{parse_str, HumanFormat};
+    let d = Duration::from_secs(5400);
+    let spaced = HumanFormat::new().unit_separator(" ");
+    assert_eq!(spaced.format(&d), "1 h 30 m");
+    assert_eq!(parse_str(&spaced.format(&d)).unwrap(), d);
+    assert_eq!(spaced.format(&Duration::ZERO), "0 s");
+    assert_eq!(HumanFormat::new().separator("").format(&d), "1h30m");
+    assert_eq!(HumanFormat::new().separator("\u{a0}").format(&d), "1h\u{a0}30m");
+    assert_eq!(HumanFormat::new().separator(", ").unit_separator(" ").format(&d), "1 h, 30 m");
+    assert_eq!(HumanFormat::default(), HumanFormat::new());
+    assert_eq!(HumanFormat::default().format(&d), "1h 30m");
+}
+
+#[test]
+fn days_threshold_rolls_short_days_into_hours() {
+    use serde_ext_duration::{parse_str, HumanFormat, Unit};
+    let days = |n: u64| Duration::from_secs(n * 86_400);
+    let week = HumanFormat::new().days_threshold(7);
+    assert_eq!(week.format(&days(2)), "48h");
+    assert_eq!(week.format(&(days(7) - Duration::from_secs(1))), "167h 59m 59s");
+    assert_eq!(week.format(&days(7)), "7d");
+    assert_eq!(week.format(&(days(8) + Duration::from_secs(3600))), "8d 1h");
+    assert_eq!(week.format(&Duration::from_secs(90)), "1m 30s");
+    assert_eq!(parse_str(&week.format(&days(3))).unwrap(), days(3));
+
+    assert_eq!(HumanFormat::new().format(&days(2)), "2d");
+    assert_eq!(HumanFormat::new().days_threshold(0).format(&days(1)), "1d");
+    assert_eq!(HumanFormat::new().days_threshold(2).format(&days(1)), "24h");
+    assert_eq!(HumanFormat::new().days_threshold(2).format(&days(2)), "2d");
+    // padding and significant units start at hours while days are rolled in
+    assert_eq!(week.pad_from(Unit::Days).format(&Duration::from_secs(300)), "0h 5m");
+    assert_eq!(week.significant_units(1).format(&(days(2) + Duration::from_secs(40 * 60))), "49h");
+}
+
+#[test]
+fn custom_unit_list_rolls_up_and_orders() {
+    use serde_ext_duration::{parse_str, HumanFormat, Unit};
+    let secs = Duration::from_secs;
+    let ms = HumanFormat::new().units(&[Unit::Minutes, Unit::Seconds]);
+    assert_eq!(ms.format(&secs(5400)), "90m");
+    assert_eq!(ms.format(&secs(5430)), "90m 30s");
+    assert_eq!(ms.format(&secs(2 * 86_400)), "2880m");
+    assert_eq!(ms.format(&Duration::from_millis(5_500)), "6s");
+    assert_eq!(ms.format(&Duration::from_millis(400)), "0s");
+    assert_eq!(parse_str(&ms.format(&secs(5430))).unwrap(), secs(5430));
+
+    let reversed = HumanFormat::new().units(&[Unit::Millis, Unit::Seconds]);
+    assert_eq!(reversed.format(&Duration::from_millis(5_250)), "250ms 5s");
+    assert_eq!(reversed.format(&secs(90)), "90s");
+    assert_eq!(parse_str(&reversed.format(&Duration::from_millis(5_250))).unwrap(), Duration::from_millis(5_250));
+
+    let hours = HumanFormat::new().units(&[Unit::Hours]);
+    assert_eq!(hours.format(&secs(90 * 60)), "2h");
+    assert_eq!(hours.format(&secs(29 * 60)), "0h");
+    assert_eq!(HumanFormat::new().units(&[]).format(&secs(86_400 + 5)), "1d 5s");
+    assert_eq!(HumanFormat::new().units(&[Unit::Days, Unit::Hours]).pad_from(Unit::Minutes).format(&secs(60)), "0h");
+}
+
+#[test]
+fn fixed_top_unit_always_shows_the_top() {
+    use serde_ext_duration::{parse_str, HumanFormat, Unit};
+    let secs = Duration::from_secs;
+    let hm = HumanFormat::new().fixed_top_unit(Unit::Hours, Unit::Minutes);
+    assert_eq!(hm.format(&secs(300)), "0h 5m");
+    assert_eq!(hm.format(&secs(90)), "0h 2m");
+    assert_eq!(hm.format(&Duration::ZERO), "0h");
+    assert_eq!(hm.format(&secs(3600 + 300)), "1h 5m");
+    // larger units roll into the top one
+    assert_eq!(hm.format(&secs(86_400 + 2 * 3600)), "26h");
+    assert_eq!(parse_str(&hm.format(&secs(300))).unwrap(), secs(300));
+
+    let hs = HumanFormat::new().fixed_top_unit(Unit::Hours, Unit::Seconds);
+    assert_eq!(hs.format(&Duration::from_millis(5_400)), "0h 0m 5s");
+    assert_eq!(hs.format(&secs(65)), "0h 1m 5s");
+    let only = HumanFormat::new().fixed_top_unit(Unit::Minutes, Unit::Minutes);
+    assert_eq!(only.format(&secs(20)), "0m");
+    assert_eq!(only.format(&secs(7200)), "120m");
+}
+
+fn secs_with_digits(d: Duration, digits: usize) -> String {
+    let mut out = Vec::new();
+    serde_ext_duration::serialize_secs_f64_with_digits(&d, digits, &mut serde_json::Serializer::new(&mut out)).unwrap();
+    String::from_utf8(out).unwrap()
+}
+
+#[test]
+fn secs_f64_with_digits_has_no_float_artifacts() {
+    let d = Duration::new;
+    assert_eq!(secs_with_digits(d(1, 234_000_000), 3), "1.234");
+    assert_eq!(secs_with_digits(d(0, 300_000_000), 3), "0.3");
+    assert_eq!(secs_with_digits(d(0, 700_000_000), 1), "0.7");
+    assert_eq!(secs_with_digits(d(4_503_599, 627_370_496), 9), "4503599.627370496");
+    assert_eq!(secs_with_digits(d(123_456_789, 123_000_000), 3), "123456789.123");
+    assert_eq!(secs_with_digits(d(86_400, 10_000_000), 2), "86400.01");
+    // rounded half-up to the requested digits
+    assert_eq!(secs_with_digits(d(1, 234_567_890), 6), "1.234568");
+    assert_eq!(secs_with_digits(d(1, 500_000_000), 0), "2.0");
+    assert_eq!(secs_with_digits(d(59, 999_500_000), 3), "60.0");
+    assert_eq!(secs_with_digits(Duration::ZERO, 3), "0.0");
+    for ms in (0..5_000).step_by(7) {
+        let out = secs_with_digits(Duration::from_millis(ms), 3);
+        let decimals = out.split_once('.').map_or(0, |(_, f)| f.len());
+        assert!(decimals <= 3, "{ms}ms -> {out}");
+    }
+}
+
+#[test]
+#[should_panic(expected = "at most 9 decimal digits")]
+fn secs_f64_with_digits_rejects_over_nine() {
+    secs_with_digits(Duration::ZERO, 10);
+}
+
+#[test]
+fn drop_subsecond_above_threshold() {
+    use serde_ext_duration::{parse_str, HumanFormat};
+    let f = HumanFormat::new().drop_subsecond_above(Duration::from_secs(60));
+    let long = Duration::from_millis(((2 * 24 + 3) * 60 + 4) * 60_000 + 5_250);
+    assert_eq!(f.format(&long), "2d 3h 4m 5s");
+    assert_eq!(HumanFormat::new().format(&long), "2d 3h 4m 5s 250ms");
+    // truncated, not rounded, and at or below the threshold nothing changes
+    assert_eq!(f.format(&Duration::from_millis(61_999)), "1m 1s");
+    assert_eq!(f.format(&Duration::from_millis(5_250)), "5s 250ms");
+    assert_eq!(f.format(&Duration::from_secs(60)), "1m");
+    assert_eq!(f.format(&Duration::from_millis(60_001)), "1m");
+    // lossy above the threshold
+    assert_ne!(parse_str(&f.format(&long)).unwrap(), long);
+}
+
+#[test]
+#[should_panic(expected = "larger than the top unit")]
+fn fixed_top_unit_rejects_an_inverted_span() {
+    use serde_ext_duration::{HumanFormat, Unit};
+    let _ = HumanFormat::new().fixed_top_unit(Unit::Seconds, Unit::Hours);
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+struct OutCentis {
+    #[serde(with = "serde_ext_duration::centis")]
+    t: Duration,
+}
+
+#[test]
+fn centis_round_half_up_at_boundaries() {
+    let out = |t| serde_json::to_string(&OutCentis { t }).unwrap();
+    assert_eq!(out(Duration::from_millis(1500)), r#"{"t":150}"#);
+    assert_eq!(out(Duration::from_nanos(4_999_999)), r#"{"t":0}"#);
+    assert_eq!(out(Duration::from_millis(5)), r#"{"t":1}"#);
+    assert_eq!(out(Duration::from_millis(14)), r#"{"t":1}"#);
+    assert_eq!(out(Duration::from_millis(15)), r#"{"t":2}"#);
+    assert_eq!(out(Duration::from_secs(u64::MAX / 100)), format!(r#"{{"t":{}}}"#, u64::MAX / 100 * 100));
+    assert!(serde_json::to_string(&OutCentis { t: Duration::MAX }).is_err());
+
+    let read = |j: &str| serde_json::from_str::<OutCentis>(j).unwrap().t;
+    assert_eq!(read(r#"{"t":150}"#), Duration::from_millis(1500));
+    assert_eq!(read(r#"{"t":1.5}"#), Duration::from_millis(1500));
+    assert_eq!(read(r#"{"t":"2s"}"#), Duration::from_secs(2));
+    let err = serde_json::from_str::<OutCentis>(r#"{"t":true}"#).unwrap_err().to_string();
+    assert!(err.contains("integer centiseconds"), "{err}");
+}
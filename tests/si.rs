@@ -0,0 +1,44 @@
+use serde::{Deserialize, Serialize};
+use serde_ext_duration::si::{parse, to_si_string};
+use std::time::Duration;
+
+#[derive(Serialize, Deserialize, Debug)]
+struct Run {
+    #[serde(with = "serde_ext_duration::si")]
+    t: Duration,
+}
+
+#[test]
+fn prefixes_are_case_sensitive() {
+    assert_eq!(parse("1ks").unwrap(), Duration::from_secs(1000));
+    assert_eq!(parse("2Ms").unwrap(), Duration::from_secs(2_000_000));
+    assert_eq!(parse("1Gs").unwrap(), Duration::from_secs(1_000_000_000));
+    assert_eq!(parse("500ms").unwrap(), Duration::from_millis(500));
+    assert_eq!(parse("3us").unwrap(), Duration::from_micros(3));
+    assert_eq!(parse("3µs").unwrap(), Duration::from_micros(3));
+    assert_eq!(parse("7ns").unwrap(), Duration::from_nanos(7));
+    assert_eq!(parse("1.5ks 250ms").unwrap(), Duration::from_millis(1_500_250));
+}
+
+#[test]
+fn no_minutes_or_days() {
+    assert!(parse("5m").unwrap_err().contains("unknown unit 'm'"));
+    assert!(parse("1d").is_err());
+    assert!(parse("1KS").is_err());
+    assert!(parse("").unwrap_err().contains("empty"));
+    assert!(parse("ks").unwrap_err().contains("expected number"));
+}
+
+#[test]
+fn serializes_in_largest_exact_prefix() {
+    assert_eq!(to_si_string(&Duration::ZERO), "0s");
+    assert_eq!(to_si_string(&Duration::from_secs(3000)), "3ks");
+    assert_eq!(to_si_string(&Duration::from_millis(1500)), "1500ms");
+    assert_eq!(to_si_string(&Duration::from_nanos(1001)), "1001ns");
+    let j = serde_json::to_string(&Run { t: Duration::from_secs(2_000_000) }).unwrap();
+    assert_eq!(j, r#"{"t":"2Ms"}"#);
+    let back: Run = serde_json::from_str(&j).unwrap();
+    assert_eq!(back.t, Duration::from_secs(2_000_000));
+    let n: Run = serde_json::from_str(r#"{"t": 5}"#).unwrap();
+    assert_eq!(n.t, Duration::from_secs(5));
+}
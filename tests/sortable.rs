@@ -0,0 +1,47 @@
+use serde::{Deserialize, Serialize};
+use serde_ext_duration::sortable::{parse, to_sortable_string, WIDTH};
+use std::time::Duration;
+
+#[derive(Serialize, Deserialize, Debug)]
+struct Key {
+    #[serde(with = "serde_ext_duration::sortable")]
+    t: Duration,
+}
+
+#[test]
+fn fixed_width_fields() {
+    assert_eq!(to_sortable_string(&Duration::from_millis(93_784_250)).unwrap(), "00001020304250");
+    assert_eq!(to_sortable_string(&Duration::ZERO).unwrap(), "00000000000000");
+    assert_eq!(to_sortable_string(&Duration::from_secs(59)).unwrap(), "00000000059000");
+    assert_eq!(to_sortable_string(&Duration::new(0, 1_500_000)).unwrap(), "00000000000002");
+    let max = Duration::from_millis(99_999 * 86_400_000 + 86_399_999);
+    assert_eq!(to_sortable_string(&max).unwrap(), "99999235959999");
+    assert!(to_sortable_string(&(max + Duration::from_millis(1))).is_err());
+    assert!(serde_json::to_string(&Key { t: Duration::MAX }).is_err());
+}
+
+#[test]
+fn lexical_order_is_chronological() {
+    let mut ds: Vec<Duration> = [0, 1, 999, 1_000, 59_999, 60_000, 3_599_999, 3_600_000, 86_399_999, 86_400_000]
+        .into_iter()
+        .chain([10 * 86_400_000, 365 * 86_400_000 + 1, 99_999 * 86_400_000])
+        .map(Duration::from_millis)
+        .collect();
+    ds.reverse();
+    let mut keys: Vec<String> = ds.iter().map(|d| to_sortable_string(d).unwrap()).collect();
+    keys.sort();
+    ds.sort();
+    assert!(keys.iter().all(|k| k.len() == WIDTH));
+    let back: Vec<Duration> = keys.iter().map(|k| parse(k).unwrap()).collect();
+    assert_eq!(back, ds);
+}
+
+#[test]
+fn parses_fixed_positions_only() {
+    let v: Key = serde_json::from_str(r#"{"t":"00001020304250"}"#).unwrap();
+    assert_eq!(v.t, Duration::from_millis(93_784_250));
+    assert_eq!(parse("0000102030425").unwrap_err(), "expected 14 digits as DDDDDHHMMSSmmm, got '0000102030425'");
+    assert!(parse("0000102030425x").is_err());
+    assert_eq!(parse("00000240000000").unwrap_err(), "clock field out of range in '00000240000000'");
+    assert!(parse("00000006000000").is_err());
+}
@@ -0,0 +1,49 @@
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+#[derive(Serialize, Deserialize, Debug)]
+struct Cfg {
+    #[serde(with = "serde_ext_duration")]
+    t: Duration,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+struct Numeric {
+    #[serde(with = "serde_ext_duration::secs")]
+    a: Duration,
+    #[serde(with = "serde_ext_duration::millis")]
+    b: Duration,
+    #[serde(with = "serde_ext_duration::secs_f64_ms")]
+    c: Duration,
+}
+
+#[test]
+fn toml_int_float_and_string() {
+    let v: Cfg = toml::from_str("t = 90").unwrap();
+    assert_eq!(v.t, Duration::from_secs(90));
+    let v: Cfg = toml::from_str("t = 90.0").unwrap();
+    assert_eq!(v.t, Duration::from_secs(90));
+    let v: Cfg = toml::from_str("t = 1.25").unwrap();
+    assert_eq!(v.t, Duration::from_millis(1250));
+    let v: Cfg = toml::from_str(r#"t = "1m 30s""#).unwrap();
+    assert_eq!(v.t, Duration::from_secs(90));
+
+    let err = toml::from_str::<Cfg>("t = -1").unwrap_err();
+    assert!(err.to_string().contains("negative"));
+}
+
+#[test]
+fn toml_round_trips() {
+    let src = Cfg { t: Duration::from_millis(3_723_250) };
+    let doc = toml::to_string(&src).unwrap();
+    assert_eq!(doc.trim(), r#"t = "1h 2m 3s 250ms""#);
+    let back: Cfg = toml::from_str(&doc).unwrap();
+    assert_eq!(back.t, src.t);
+
+    let src = Numeric { a: Duration::from_secs(5), b: Duration::from_millis(1500), c: Duration::from_millis(2250) };
+    let doc = toml::to_string(&src).unwrap();
+    assert!(doc.contains("a = 5") && doc.contains("b = 1500") && doc.contains("c = 2.25"));
+    let back: Numeric = toml::from_str(&doc).unwrap();
+    // integers always read back as seconds, so `millis` output is not symmetric
+    assert_eq!((back.a, back.b, back.c), (src.a, Duration::from_secs(1500), src.c));
+}
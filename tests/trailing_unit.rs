@@ -0,0 +1,52 @@
+use serde::Deserialize;
+use serde_ext_duration::trailing_unit::parse;
+use std::time::Duration;
+
+#[derive(Deserialize, Debug)]
+struct Cfg {
+    #[serde(with = "serde_ext_duration::trailing_unit")]
+    t: Duration,
+}
+
+#[test]
+fn trailing_number_steps_down_one_unit() {
+    let secs = Duration::from_secs;
+    assert_eq!(parse("1h30").unwrap(), secs(5400));
+    assert_eq!(parse("1m30").unwrap(), secs(90));
+    assert_eq!(parse("2d 12").unwrap(), secs(2 * 86_400 + 12 * 3600));
+    assert_eq!(parse("1w2").unwrap(), secs(9 * 86_400));
+    assert_eq!(parse("1s250").unwrap(), Duration::from_millis(1250));
+    assert_eq!(parse("1h 5m 30").unwrap(), secs(3600 + 5 * 60 + 30));
+    assert_eq!(parse("1 hour 30").unwrap(), secs(5400));
+    assert_eq!(parse("1m30.5").unwrap(), Duration::from_millis(90_500));
+}
+
+#[test]
+fn strings_ending_in_a_unit_are_unchanged() {
+    assert_eq!(parse("1h30m").unwrap(), Duration::from_secs(5400));
+    assert_eq!(parse(" 250ms ").unwrap(), Duration::from_millis(250));
+}
+
+#[test]
+fn edge_cases_are_errors() {
+    let err = parse("30").unwrap_err();
+    assert!(err.contains("needs a preceding unit"), "{err}");
+    let err = parse("250ms5").unwrap_err();
+    assert!(err.contains("no smaller unit"), "{err}");
+    assert!(parse("5 30").is_err());
+    assert!(parse("").is_err());
+    assert!(parse("1x30").is_err());
+    // only the last number may drop its unit
+    assert!(parse("1h30 15s").is_err());
+}
+
+#[test]
+fn serde_uses_the_rule() {
+    let v: Cfg = serde_json::from_str(r#"{"t":"1h30"}"#).unwrap();
+    assert_eq!(v.t, Duration::from_secs(5400));
+    let v: Cfg = serde_json::from_str(r#"{"t":30}"#).unwrap();
+    assert_eq!(v.t, Duration::from_secs(30));
+    let v: Cfg = serde_json::from_str(r#"{"t":"30"}"#).unwrap();
+    assert_eq!(v.t, Duration::from_secs(30));
+    assert!(serde_json::from_str::<Cfg>(r#"{"t":"250ms5"}"#).is_err());
+}
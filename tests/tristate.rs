@@ -0,0 +1,46 @@
+use serde::{Deserialize, Serialize};
+use serde_ext_duration::Tristate;
+use std::time::Duration;
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct Cfg {
+    #[serde(default)]
+    grace: Tristate,
+}
+
+fn read(json: &str) -> Tristate {
+    serde_json::from_str::<Cfg>(json).unwrap().grace
+}
+
+#[test]
+fn three_states() {
+    assert_eq!(read(r#"{}"#), Tristate::Unset);
+    assert_eq!(read(r#"{"grace":null}"#), Tristate::Unset);
+    assert_eq!(read(r#"{"grace":0}"#), Tristate::Zero);
+    assert_eq!(read(r#"{"grace":"0s"}"#), Tristate::Zero);
+    assert_eq!(read(r#"{"grace":"0ms"}"#), Tristate::Zero);
+    assert_eq!(read(r#"{"grace":0.0}"#), Tristate::Zero);
+    assert_eq!(read(r#"{"grace":"30s"}"#), Tristate::Value(Duration::from_secs(30)));
+    assert_eq!(read(r#"{"grace":1}"#), Tristate::Value(Duration::from_secs(1)));
+    assert!(serde_json::from_str::<Cfg>(r#"{"grace":"soon"}"#).is_err());
+}
+
+#[test]
+fn duration_accessor() {
+    assert_eq!(Tristate::Unset.duration(), None);
+    assert_eq!(Tristate::Zero.duration(), Some(Duration::ZERO));
+    assert_eq!(Tristate::Value(Duration::from_secs(5)).duration(), Some(Duration::from_secs(5)));
+}
+
+#[test]
+fn serializes_each_state() {
+    for (grace, json) in [
+        (Tristate::Unset, r#"{"grace":null}"#),
+        (Tristate::Zero, r#"{"grace":"0s"}"#),
+        (Tristate::Value(Duration::from_secs(90)), r#"{"grace":"1m 30s"}"#),
+    ] {
+        let s = serde_json::to_string(&Cfg { grace }).unwrap();
+        assert_eq!(s, json);
+        assert_eq!(read(&s), grace);
+    }
+}
@@ -0,0 +1,29 @@
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+#[derive(Serialize, Deserialize, Debug)]
+struct Rec {
+    #[serde(with = "serde_ext_duration::tuple_repr")]
+    t: Duration,
+}
+
+#[test]
+fn secs_nanos_pair_round_trip() {
+    let j = serde_json::to_string(&Rec { t: Duration::new(5, 250_000_000) }).unwrap();
+    assert_eq!(j, r#"{"t":[5,250000000]}"#);
+    let back: Rec = serde_json::from_str(&j).unwrap();
+    assert_eq!(back.t, Duration::new(5, 250_000_000));
+    let carried: Rec = serde_json::from_str(r#"{"t":[1,1500000000]}"#).unwrap();
+    assert_eq!(carried.t, Duration::new(2, 500_000_000));
+}
+
+#[test]
+fn rejects_other_lengths_and_overflow() {
+    for bad in [r#"{"t":[]}"#, r#"{"t":[5]}"#, r#"{"t":[5,0,0]}"#] {
+        let err = serde_json::from_str::<Rec>(bad).unwrap_err().to_string();
+        assert!(err.contains("invalid length"), "{bad}: {err}");
+    }
+    let max = format!(r#"{{"t":[{},1000000000]}}"#, u64::MAX);
+    assert!(serde_json::from_str::<Rec>(&max).unwrap_err().to_string().contains("duration overflow"));
+    assert!(serde_json::from_str::<Rec>(r#"{"t":"5s"}"#).is_err());
+}
@@ -0,0 +1,53 @@
+use serde::{Deserialize, Serialize};
+use serde_ext_duration::unit_first::{parse, to_unit_first_string};
+use std::time::Duration;
+
+#[derive(Serialize, Deserialize, Debug)]
+struct Cfg {
+    #[serde(with = "serde_ext_duration::unit_first")]
+    t: Duration,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+struct OptCfg {
+    #[serde(default, with = "serde_ext_duration::opt::unit_first")]
+    t: Option<Duration>,
+}
+
+#[test]
+fn sums_unit_first_tokens() {
+    assert_eq!(parse("h1 m30").unwrap(), Duration::from_secs(90 * 60));
+    assert_eq!(parse("s30 m5").unwrap(), Duration::from_secs(330));
+    assert_eq!(parse("h1m30").unwrap(), Duration::from_secs(90 * 60));
+    assert_eq!(parse(" H1.5 ").unwrap(), Duration::from_secs(5400));
+    assert_eq!(parse("d1 ms250").unwrap(), Duration::from_millis(86_400_250));
+    let v: Cfg = serde_json::from_str(r#"{"t":"m2"}"#).unwrap();
+    assert_eq!(v.t, Duration::from_secs(120));
+}
+
+#[test]
+fn rejects_number_first_and_mixed_orders() {
+    assert_eq!(parse("1h").unwrap_err(), "expected unit before number at position 0 (unit-first, e.g. 'h1')");
+    assert_eq!(parse("h1 30m").unwrap_err(), "expected unit before number at position 3 (unit-first, e.g. 'h1')");
+    assert_eq!(parse("h").unwrap_err(), "expected number after unit 'h' at position 0");
+    assert_eq!(parse("x1").unwrap_err(), "unknown unit 'x' (this format takes d, h, m, s, ms)");
+    assert_eq!(parse("h1.").unwrap_err(), "invalid number '1.' for unit 'h'");
+    assert_eq!(parse("").unwrap_err(), "empty duration string");
+    assert!(serde_json::from_str::<Cfg>(r#"{"t":"1h"}"#).is_err());
+}
+
+#[test]
+fn round_trips_and_opt() {
+    assert_eq!(to_unit_first_string(&Duration::from_secs(5415)), "h1 m30 s15");
+    assert_eq!(to_unit_first_string(&Duration::ZERO), "s0");
+    let json = serde_json::to_string(&Cfg { t: Duration::from_millis(86_400_250) }).unwrap();
+    assert_eq!(json, r#"{"t":"d1 ms250"}"#);
+    let back: Cfg = serde_json::from_str(&json).unwrap();
+    assert_eq!(back.t, Duration::from_millis(86_400_250));
+
+    let v: OptCfg = serde_json::from_str(r#"{"t":"h1 m30"}"#).unwrap();
+    assert_eq!(v.t, Some(Duration::from_secs(90 * 60)));
+    let v: OptCfg = serde_json::from_str("{}").unwrap();
+    assert_eq!(v.t, None);
+    assert_eq!(serde_json::to_string(&OptCfg { t: None }).unwrap(), r#"{"t":null}"#);
+}
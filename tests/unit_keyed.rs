@@ -0,0 +1,38 @@
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+#[derive(Serialize, Deserialize, Debug)]
+struct Cfg {
+    #[serde(with = "serde_ext_duration::unit_keyed")]
+    t: Duration,
+}
+
+fn parse(json: &str) -> Result<Duration, String> {
+    serde_json::from_str::<Cfg>(&format!(r#"{{"t":{json}}}"#)).map(|c| c.t).map_err(|e| e.to_string())
+}
+
+#[test]
+fn unit_key_scales_value() {
+    assert_eq!(parse(r#"{"h": 1}"#).unwrap(), Duration::from_secs(3600));
+    assert_eq!(parse(r#"{"ms": 250}"#).unwrap(), Duration::from_millis(250));
+    assert_eq!(parse(r#"{"min": 5}"#).unwrap(), Duration::from_secs(300));
+    assert_eq!(parse(r#"{"Hours": 1.5}"#).unwrap(), Duration::from_secs(5400));
+    assert_eq!(parse(r#"{"secs": 0}"#).unwrap(), Duration::ZERO);
+}
+
+#[test]
+fn rejects_ambiguous_or_unknown_maps() {
+    assert!(parse(r#"{"h": 1, "m": 30}"#).unwrap_err().contains("single unit key"));
+    assert!(parse(r#"{}"#).unwrap_err().contains("invalid length 0"));
+    assert!(parse(r#"{"fortnight": 1}"#).unwrap_err().contains("unknown unit 'fortnight'"));
+    assert!(parse(r#"{"h": -1}"#).is_err());
+    assert!(parse(r#"{"h": "1"}"#).is_err());
+    assert!(parse(r#""1h""#).is_err());
+}
+
+#[test]
+fn serializes_coarsest_exact_unit() {
+    let j = serde_json::to_string(&Cfg { t: Duration::from_secs(5400) }).unwrap();
+    assert_eq!(j, r#"{"t":{"m":90}}"#);
+    assert_eq!(serde_json::from_str::<Cfg>(&j).unwrap().t, Duration::from_secs(5400));
+}
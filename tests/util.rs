@@ -0,0 +1,166 @@
+use serde::Deserialize;
+use serde_ext_duration::{Accumulator, OverflowError};
+use std::time::Duration;
+
+#[derive(Deserialize)]
+struct Record {
+    #[serde(with = "serde_ext_duration")]
+    took: Duration,
+}
+
+#[test]
+fn accumulator_sums_stream() {
+    let mut acc = Accumulator::new();
+    for line in [r#"{"took":"1m"}"#, r#"{"took":30}"#, r#"{"took":"250ms"}"#] {
+        let r: Record = serde_json::from_str(line).unwrap();
+        acc.add(&r.took).unwrap();
+    }
+    assert_eq!(acc.total(), Duration::from_millis(90_250));
+}
+
+#[test]
+fn accumulator_overflow_is_an_error() {
+    let mut acc = Accumulator::new();
+    acc.add(&Duration::MAX).unwrap();
+    assert_eq!(acc.add(&Duration::from_nanos(1)), Err(OverflowError));
+    assert_eq!(acc.total(), Duration::MAX);
+    assert_eq!(OverflowError.to_string(), "duration overflow");
+}
+
+#[test]
+fn round_to_granularity_half_up() {
+    use serde_ext_duration::round_to;
+    let five = Duration::from_secs(300);
+    assert_eq!(round_to(Duration::from_secs(7 * 60), five), five);
+    assert_eq!(round_to(Duration::from_secs(8 * 60), five), Duration::from_secs(600));
+    assert_eq!(round_to(Duration::from_secs(7 * 60 + 30), five), Duration::from_secs(600));
+    assert_eq!(round_to(Duration::from_millis(1499), Duration::from_secs(1)), Duration::from_secs(1));
+    assert_eq!(round_to(Duration::from_millis(1234), Duration::ZERO), Duration::from_millis(1234));
+
+    // never overflows: falls back to the lower multiple
+    let day = Duration::from_secs(86_400);
+    let r = round_to(Duration::MAX, day);
+    assert!(r <= Duration::MAX && Duration::MAX - r < day);
+}
+
+mod nearest_5m {
+    use serde::Serializer;
+    use std::time::Duration;
+    pub fn serialize<S: Serializer>(d: &Duration, s: S) -> Result<S::Ok, S::Error> {
+        serde_ext_duration::rounded::serialize(d, Duration::from_secs(300), s)
+    }
+}
+
+#[derive(serde::Serialize)]
+struct Display {
+    #[serde(serialize_with = "nearest_5m::serialize")]
+    eta: Duration,
+}
+
+#[test]
+fn rounded_serializes_human() {
+    let j = serde_json::to_string(&Display { eta: Duration::from_secs(7 * 60) }).unwrap();
+    assert_eq!(j, r#"{"eta":"5m"}"#);
+    let j = serde_json::to_string(&Display { eta: Duration::from_secs(58 * 60) }).unwrap();
+    assert_eq!(j, r#"{"eta":"1h"}"#);
+}
+
+#[test]
+fn scale_by_factor_never_panics() {
+    use serde_ext_duration::{scale, ScaleError};
+    let base = Duration::from_secs(10);
+    assert_eq!(scale(base, 1.5), Ok(Duration::from_secs(15)));
+    assert_eq!(scale(base, 0.0), Ok(Duration::ZERO));
+    assert_eq!(scale(Duration::from_nanos(3), 0.5), Ok(Duration::from_nanos(2)));
+    assert_eq!(scale(base, f64::NAN), Err(ScaleError::InvalidFactor));
+    assert_eq!(scale(base, f64::INFINITY), Err(ScaleError::InvalidFactor));
+    assert_eq!(scale(base, -1.0), Err(ScaleError::InvalidFactor));
+    assert_eq!(scale(Duration::MAX, 2.0), Err(ScaleError::Overflow));
+    assert_eq!(scale(Duration::from_secs(u64::MAX / 2 + 1), 2.0), Err(ScaleError::Overflow));
+    assert_eq!(ScaleError::Overflow.to_string(), "duration overflow");
+}
+
+#[test]
+fn ext_duration_sum_saturates() {
+    use serde_ext_duration::ExtDuration;
+    let ds = [Duration::from_secs(60), Duration::from_millis(500)];
+    assert_eq!(ds.iter().sum::<ExtDuration>(), ExtDuration(Duration::from_millis(60_500)));
+    assert_eq!(ds.into_iter().sum::<ExtDuration>().0, Duration::from_millis(60_500));
+    assert_eq!(std::iter::empty::<Duration>().sum::<ExtDuration>().0, Duration::ZERO);
+
+    let huge = [Duration::MAX, Duration::from_secs(1), Duration::MAX];
+    assert_eq!(huge.iter().sum::<ExtDuration>().0, Duration::MAX);
+    let ext = [ExtDuration(Duration::MAX), ExtDuration(Duration::from_nanos(1))];
+    assert_eq!(ext.into_iter().sum::<ExtDuration>().0, Duration::MAX);
+}
+
+#[test]
+fn bucket_at_boundaries() {
+    use serde_ext_duration::{bucket, bucket_label};
+    let ms = Duration::from_millis;
+    let bounds = [ms(100), ms(250), ms(1000)];
+    assert_eq!(bucket(Duration::ZERO, &bounds), 0);
+    assert_eq!(bucket(ms(100), &bounds), 0);
+    assert_eq!(bucket(ms(100) + Duration::from_nanos(1), &bounds), 1);
+    assert_eq!(bucket(ms(250), &bounds), 1);
+    assert_eq!(bucket(ms(1000), &bounds), 2);
+    assert_eq!(bucket(ms(1001), &bounds), 3);
+    assert_eq!(bucket(Duration::MAX, &bounds), 3);
+    assert_eq!(bucket(ms(5), &[]), 0);
+
+    let labels: Vec<_> = (0..=bounds.len()).map(|i| bucket_label(&bounds, i)).collect();
+    assert_eq!(labels, ["100ms", "250ms", "1s", "+Inf"]);
+    assert_eq!(bucket_label(&[], 0), "+Inf");
+}
+
+#[test]
+#[should_panic(expected = "bucket index 4 out of range")]
+fn bucket_label_rejects_bad_index() {
+    serde_ext_duration::bucket_label(&[Duration::from_secs(1); 3], 4);
+}
+
+#[test]
+fn validate_all_reports_every_failure() {
+    use serde_ext_duration::validate_all;
+    let inputs = ["5s", "1h 30m", "5x", "", "1.5h", "-3s", "250ms"];
+    let results = validate_all(inputs);
+    assert_eq!(results.len(), inputs.len());
+    let ok: Vec<_> = results.iter().filter_map(|(i, r)| r.as_ref().ok().map(|d| (*i, *d))).collect();
+    assert_eq!(
+        ok,
+        [
+            (0, Duration::from_secs(5)),
+            (1, Duration::from_secs(5400)),
+            (4, Duration::from_secs(5400)),
+            (6, Duration::from_millis(250)),
+        ]
+    );
+    let errs: Vec<_> = results.iter().filter_map(|(i, r)| r.as_ref().err().map(|e| (*i, e))).collect();
+    assert_eq!(errs.iter().map(|(i, _)| *i).collect::<Vec<_>>(), [2, 3, 5]);
+    let (_, e) = errs[0];
+    assert_eq!(e.input(), "5x");
+    assert_eq!(e.message(), "unknown unit 'x' (e.g. d, h, m, s, ms, hours, µs; see `units()`)");
+    assert_eq!(errs[1].1.message(), "empty duration string");
+    assert!(validate_all(Vec::<&str>::new()).is_empty());
+}
+
+#[test]
+fn ext_duration_compares_with_duration() {
+    use serde_ext_duration::ExtDuration;
+    let ext: ExtDuration = serde_json::from_str(r#""5s""#).unwrap();
+    assert_eq!(ext, Duration::from_secs(5));
+    assert_eq!(Duration::from_secs(5), ext);
+    assert_ne!(ext, Duration::from_millis(5001));
+    assert_ne!(Duration::ZERO, ext);
+}
+
+#[test]
+fn ext_duration_derefs_to_duration() {
+    use serde_ext_duration::ExtDuration;
+    let ext: ExtDuration = serde_json::from_str(r#""1m 30s 250ms""#).unwrap();
+    assert_eq!(ext.as_secs(), 90);
+    assert_eq!(ext.subsec_millis(), 250);
+    assert_eq!(*ext + Duration::from_millis(750), Duration::from_secs(91));
+    let as_ref: &Duration = ext.as_ref();
+    assert_eq!(as_ref, &Duration::from_millis(90_250));
+}
@@ -0,0 +1,36 @@
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+#[derive(Serialize, Deserialize, Debug)]
+struct Cfg {
+    #[serde(with = "serde_ext_duration::wrapped")]
+    t: Duration,
+}
+
+#[test]
+fn unwraps_value_and_duration_keys() {
+    let v: Cfg = serde_json::from_str(r#"{ "t": { "value": "5m" } }"#).unwrap();
+    assert_eq!(v.t, Duration::from_secs(300));
+    let v: Cfg = serde_json::from_str(r#"{ "t": { "duration": 1.5 } }"#).unwrap();
+    assert_eq!(v.t, Duration::from_millis(1500));
+    let v: Cfg = serde_yaml::from_str("t:\n  value: 42").unwrap();
+    assert_eq!(v.t, Duration::from_secs(42));
+}
+
+#[test]
+fn rejects_unknown_or_extra_keys() {
+    let err = serde_json::from_str::<Cfg>(r#"{ "t": { "secs": 5 } }"#).unwrap_err();
+    assert!(err.to_string().contains("unknown field"));
+    let err = serde_json::from_str::<Cfg>(r#"{ "t": { "value": "5m", "duration": "1m" } }"#).unwrap_err();
+    assert!(err.to_string().contains("single key"));
+    assert!(serde_json::from_str::<Cfg>(r#"{ "t": {} }"#).is_err());
+    assert!(serde_json::from_str::<Cfg>(r#"{ "t": "5m" }"#).is_err());
+}
+
+#[test]
+fn serializes_as_value_envelope_and_round_trips() {
+    let j = serde_json::to_string(&Cfg { t: Duration::from_secs(90) }).unwrap();
+    assert_eq!(j, r#"{"t":{"value":"1m 30s"}}"#);
+    let back: Cfg = serde_json::from_str(&j).unwrap();
+    assert_eq!(back.t, Duration::from_secs(90));
+}